@@ -0,0 +1,68 @@
+use std::{env, fs, path::Path};
+
+/// Generates a test for each part of each example in `examples/<year>/`: an input
+/// `dayNN-<name>.txt` with the expected answers in `dayNN-<name>.toml` next to it,
+/// under the same `part1` and `part2` keys as the answers files. Only the parts the
+/// `.toml` file has an answer for are tested.
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+
+    let mut tests = String::new();
+    for (year, day, name, parts) in examples(Path::new("examples")) {
+        let test_name = format!("y{year}_{name}")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        for part in parts {
+            tests.push_str(&format!(
+                "#[test]\nfn {test_name}_part{part}() {{\n    check({year}, {day}, {name:?}, {part});\n}}\n\n"
+            ));
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// Every example as its year, day, file name without extension and answered parts.
+fn examples(dir: &Path) -> Vec<(u32, usize, String, Vec<u8>)> {
+    let mut examples = vec![];
+    let Ok(years) = fs::read_dir(dir) else {
+        return examples;
+    };
+
+    for year_dir in years.flatten() {
+        let Some(year) = year_dir.file_name().to_str().and_then(|y| y.parse().ok()) else {
+            continue;
+        };
+        for file in fs::read_dir(year_dir.path()).unwrap().flatten() {
+            let path = file.path();
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                continue;
+            }
+
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let Some(day) = name
+                .strip_prefix("day")
+                .and_then(|rest| rest.split('-').next())
+                .and_then(|day| day.parse().ok())
+            else {
+                panic!("{}: expected a dayNN-<name>.toml file", path.display());
+            };
+
+            let answers = fs::read_to_string(&path).unwrap();
+            let parts = [1, 2]
+                .into_iter()
+                .filter(|part| {
+                    answers
+                        .lines()
+                        .any(|line| line.trim_start().starts_with(&format!("part{part}")))
+                })
+                .collect();
+            examples.push((year, day, name, parts));
+        }
+    }
+
+    examples.sort();
+    examples
+}
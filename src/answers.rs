@@ -0,0 +1,217 @@
+use std::path::PathBuf;
+
+use crate::days::{Answer, Part};
+use crate::inputs::YearDir;
+
+/// Environment variable overriding the answers directory.
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+pub const DEFAULT_DIR: &str = "answers";
+
+/// The known answers for a day, read from a `dayNN.toml` file such as:
+///
+/// ```toml
+/// part1 = 11
+/// part2 = "5,7,3,0"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut expected = Self::default();
+
+        for (line, key, value) in parse_pairs(input)? {
+            match key {
+                "part1" => expected.part1 = Some(value),
+                "part2" => expected.part2 = Some(value),
+                key => return Err(format!("line {line}: unknown key `{key}`")),
+            }
+        }
+
+        Ok(expected)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Parses `key = value` lines, skipping blank lines and `#` comments, into each line
+/// number with its key and value. Values are integers or quoted strings.
+pub fn parse_pairs(input: &str) -> Result<Vec<(usize, &str, String)>, String> {
+    let mut pairs = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+        let value = parse_value(value.trim()).ok_or_else(|| {
+            format!(
+                "line {}: expected an integer or a quoted string, found `{}`",
+                i + 1,
+                value.trim()
+            )
+        })?;
+        pairs.push((i + 1, key.trim(), value));
+    }
+
+    Ok(pairs)
+}
+
+/// Parses an integer or a quoted string, with `\` escaping the next character, up to
+/// an optional `#` comment. A `#` inside the quotes is part of the string.
+fn parse_value(value: &str) -> Option<String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        let value = match value.split_once(" #") {
+            Some((value, _comment)) => value.trim_end(),
+            None => value,
+        };
+        return value.parse::<i64>().ok().map(|n| n.to_string());
+    };
+
+    let mut s = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => s.push(chars.next()?.1),
+            '"' => {
+                let rest = quoted[i + 1..].trim_start();
+                return (rest.is_empty() || rest.starts_with('#')).then_some(s);
+            }
+            c => s.push(c),
+        }
+    }
+
+    None
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+    Unsolved,
+    Failed(String),
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, answer: &Answer) -> Self {
+        match (expected, answer) {
+            (_, Answer::Unsolved) => Verdict::Unsolved,
+            (_, Answer::Failed(reason)) => Verdict::Failed(reason.clone()),
+            (None, _) => Verdict::Missing,
+            (Some(expected), answer) if answer.to_string() == expected => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail(expected.to_string()),
+        }
+    }
+}
+
+/// Finds answer files the same way [`crate::inputs::Inputs`] finds inputs:
+/// `<dir>/<year>/dayNN.toml`, see [`YearDir`].
+pub struct Answers {
+    files: YearDir,
+}
+
+impl Answers {
+    /// Uses `dir` if given, then the `AOC_ANSWERS` environment variable, then `answers`.
+    pub fn from_env(dir: Option<&str>, year: u32) -> Self {
+        Self {
+            files: YearDir::from_env(dir, ANSWERS_ENV, DEFAULT_DIR, year),
+        }
+    }
+
+    fn file_name(day: usize, example: bool) -> String {
+        if example {
+            format!("day{day:02}.example.toml")
+        } else {
+            format!("day{day:02}.toml")
+        }
+    }
+
+    pub fn candidates(&self, day: usize, example: bool) -> Vec<PathBuf> {
+        self.files.candidates(&Self::file_name(day, example))
+    }
+
+    /// Where submitted answers are recorded: `<dir>/<year>/submissions.csv`.
+    pub fn history_path(&self) -> PathBuf {
+        self.files.year_path("submissions.csv")
+    }
+
+    pub fn find(&self, day: usize, example: bool) -> Option<PathBuf> {
+        self.files.find(&Self::file_name(day, example))
+    }
+
+    /// Loads the answers for a day. A day without an answers file has no known answers.
+    pub fn load(&self, day: usize, example: bool) -> Result<Expected, String> {
+        let Some(path) = self.find(day, example) else {
+            return Ok(Expected::default());
+        };
+
+        let contents =
+            std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Expected::parse(&contents).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod answers_test {
+    use super::{Expected, Verdict};
+    use crate::days::Answer;
+
+    #[test]
+    fn test_parse() {
+        let expected = Expected::parse(
+            "# day 17\npart1 = \"4,6,3,5,6,3,5,2,1,0\"\n\npart2 = 117440 # from the example\n",
+        )
+        .unwrap();
+        assert_eq!(expected.part1.as_deref(), Some("4,6,3,5,6,3,5,2,1,0"));
+        assert_eq!(expected.part2.as_deref(), Some("117440"));
+
+        assert_eq!(Expected::parse("part2 = 5").unwrap().part1, None);
+        assert!(Expected::parse("part3 = 5").is_err());
+        assert!(Expected::parse("part1 = five").is_err());
+        assert!(Expected::parse("part1").is_err());
+    }
+
+    #[test]
+    fn test_quoted() {
+        let part1 = |input: &str| Expected::parse(input).map(|e| e.part1);
+        assert_eq!(part1("part1 = \"a #b\""), Ok(Some("a #b".to_string())));
+        assert_eq!(part1("part1 = \"a #b\" # c"), Ok(Some("a #b".to_string())));
+        assert_eq!(
+            part1(r#"part1 = "say \"hi\" \\ # no""#),
+            Ok(Some(r#"say "hi" \ # no"#.to_string()))
+        );
+        assert!(part1("part1 = \"a #b").is_err());
+        assert!(part1("part1 = \"a\" b").is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::new(Some("11"), &Answer::Int(11)), Verdict::Pass);
+        assert_eq!(
+            Verdict::new(Some("12"), &Answer::Int(11)),
+            Verdict::Fail("12".to_string())
+        );
+        assert_eq!(Verdict::new(None, &Answer::Int(11)), Verdict::Missing);
+        assert_eq!(
+            Verdict::new(Some("1"), &Answer::Unsolved),
+            Verdict::Unsolved
+        );
+        assert_eq!(
+            Verdict::new(Some("1"), &Answer::Failed("loops".to_string())),
+            Verdict::Failed("loops".to_string())
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::bench::DayBench;
+use crate::days::Part;
+
+pub const DEFAULT_DIR: &str = "baselines";
+/// Slowdown, in percent of the baseline median, above which a step counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    pub fn from_part(part: Part) -> Self {
+        match part {
+            Part::One => Step::Part1,
+            Part::Two => Step::Part2,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::Part1 => "1",
+            Step::Part2 => "2",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "parse" => Some(Step::Parse),
+            "1" => Some(Step::Part1),
+            "2" => Some(Step::Part2),
+            _ => None,
+        }
+    }
+}
+
+/// The first line of a baseline file.
+const HEADER: &str = "year,day,step,median_ns";
+
+/// Median timings of a benchmark run, by year, day and step, saved under a name so
+/// later runs can be compared against it. Stored as `<dir>/<name>.csv`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, usize, Step), Duration>,
+}
+
+impl Baseline {
+    pub fn path(dir: &Path, name: &str) -> Result<PathBuf, String> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            && !name.starts_with('.');
+        if !valid {
+            return Err(format!(
+                "invalid baseline name '{name}', use letters, digits, '-', '_' and '.'"
+            ));
+        }
+
+        Ok(dir.join(format!("{name}.csv")))
+    }
+
+    pub fn load(dir: &Path, name: &str) -> Result<Self, String> {
+        let path = Self::path(dir, name)?;
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| format!("baseline {}: {e}", path.display()))?;
+        Self::parse(&contents).map_err(|e| format!("baseline {}: {e}", path.display()))
+    }
+
+    /// Saves `benches`, of days in `year`, as the baseline `name`. Days that were not
+    /// benchmarked keep the timings already stored under that name.
+    pub fn save(
+        dir: &Path,
+        name: &str,
+        year: u32,
+        benches: &[DayBench],
+    ) -> Result<PathBuf, String> {
+        let path = Self::path(dir, name)?;
+        let mut baseline = if path.is_file() {
+            Self::load(dir, name)?
+        } else {
+            Self::default()
+        };
+        baseline.update(year, benches);
+
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        std::fs::write(&path, baseline.to_csv()).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        Ok(path)
+    }
+
+    pub fn update(&mut self, year: u32, benches: &[DayBench]) {
+        for bench in benches {
+            self.medians
+                .insert((year, bench.day, Step::Parse), bench.parse.median);
+            for p in &bench.parts {
+                self.medians
+                    .insert((year, bench.day, Step::from_part(p.part)), p.stats.median);
+            }
+        }
+    }
+
+    pub fn get(&self, year: u32, day: usize, step: Step) -> Option<Duration> {
+        self.medians.get(&(year, day, step)).copied()
+    }
+
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut medians = BTreeMap::new();
+        if input
+            .lines()
+            .next()
+            .is_some_and(|header| header.trim() != HEADER)
+        {
+            return Err(format!(
+                "line 1: expected `{HEADER}`, save the baseline again to add years"
+            ));
+        }
+
+        for (i, line) in input.lines().enumerate() {
+            if i == 0 || line.trim().is_empty() {
+                continue;
+            }
+
+            let mut fields = line.split(',');
+            let (Some(year), Some(day), Some(step), Some(median), None) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                return Err(format!("line {}: expected {HEADER}", i + 1));
+            };
+
+            let year = year
+                .parse()
+                .map_err(|_| format!("line {}: invalid year '{year}'", i + 1))?;
+            let day = day
+                .parse()
+                .map_err(|_| format!("line {}: invalid day '{day}'", i + 1))?;
+            let step = Step::parse(step)
+                .ok_or_else(|| format!("line {}: invalid step '{step}'", i + 1))?;
+            let median = median
+                .parse()
+                .map_err(|_| format!("line {}: invalid median '{median}'", i + 1))?;
+
+            medians.insert((year, day, step), Duration::from_nanos(median));
+        }
+
+        Ok(Self { medians })
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = format!("{HEADER}\n");
+        for ((year, day, step), median) in &self.medians {
+            out.push_str(&format!(
+                "{year},{day},{},{}\n",
+                step.name(),
+                median.as_nanos()
+            ));
+        }
+        out
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: usize,
+    pub step: Step,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Change relative to the baseline, in percent. Positive means slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Compares the medians of `benches`, of days in `year`, against `baseline`. Steps
+/// missing from the baseline are skipped.
+pub fn compare(
+    baseline: &Baseline,
+    year: u32,
+    benches: &[DayBench],
+    threshold: f64,
+) -> Vec<Comparison> {
+    let mut comparisons = vec![];
+    for bench in benches {
+        let steps = std::iter::once((Step::Parse, bench.parse.median)).chain(
+            bench
+                .parts
+                .iter()
+                .map(|p| (Step::from_part(p.part), p.stats.median)),
+        );
+
+        for (step, current) in steps {
+            let Some(baseline) = baseline.get(year, bench.day, step) else {
+                continue;
+            };
+
+            let base = baseline.as_nanos().max(1) as f64;
+            let change = (current.as_nanos() as f64 - base) / base * 100.0;
+            comparisons.push(Comparison {
+                day: bench.day,
+                step,
+                baseline,
+                current,
+                change,
+                regressed: change > threshold,
+            });
+        }
+    }
+
+    comparisons
+}
+
+pub fn print_comparisons(name: &str, comparisons: &[Comparison], threshold: f64) {
+    println!("Compared against baseline '{name}' (threshold {threshold}%)");
+    println!(
+        "{:<6} {:<6} {:>12} {:>12} {:>9}",
+        "Day", "step", "baseline", "current", "change"
+    );
+    for c in comparisons {
+        println!(
+            "{:<6} {:<6} {:>12.2?} {:>12.2?} {:>+8.1}%{}",
+            c.day,
+            c.step.name(),
+            c.baseline,
+            c.current,
+            c.change,
+            if c.regressed { "  REGRESSION" } else { "" }
+        );
+    }
+}
+
+#[cfg(test)]
+mod baseline_test {
+    use std::time::Duration;
+
+    use super::{compare, Baseline, Step};
+    use crate::bench::{DayBench, PartBench, Stats};
+    use crate::days::{Answer, Part};
+
+    fn stats(micros: u64) -> Stats {
+        Stats::from_samples(&[Duration::from_micros(micros)])
+    }
+
+    fn bench(day: usize, parse: u64, part1: u64) -> DayBench {
+        DayBench {
+            day,
+            parse: stats(parse),
+            parts: vec![PartBench {
+                part: Part::One,
+                answer: Answer::Int(0),
+                stats: stats(part1),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.update(2024, &[bench(3, 10, 20), bench(12, 1, 2)]);
+        baseline.update(2023, &[bench(3, 30, 40)]);
+
+        let csv = baseline.to_csv();
+        assert_eq!(
+            csv,
+            "year,day,step,median_ns\n2023,3,parse,30000\n2023,3,1,40000\n\
+             2024,3,parse,10000\n2024,3,1,20000\n2024,12,parse,1000\n2024,12,1,2000\n"
+        );
+        assert_eq!(Baseline::parse(&csv), Ok(baseline));
+        assert!(Baseline::parse("year,day,step,median_ns\n2024,3,part,5\n").is_err());
+        assert!(Baseline::parse("year,day,step,median_ns\n3,parse,5\n").is_err());
+        assert_eq!(
+            Baseline::parse("day,step,median_ns\n3,parse,5\n"),
+            Err(
+                "line 1: expected `year,day,step,median_ns`, save the baseline again to add years"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.update(2024, &[bench(3, 100, 100)]);
+
+        let comparisons = compare(&baseline, 2024, &[bench(3, 105, 150), bench(4, 1, 1)], 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].step, Step::Parse);
+        assert!(!comparisons[0].regressed);
+        assert_eq!(comparisons[1].step, Step::Part1);
+        assert!(comparisons[1].regressed);
+        assert_eq!(comparisons[1].change, 50.0);
+
+        // Another year's day 3 is a different day.
+        assert!(compare(&baseline, 2023, &[bench(3, 105, 150)], 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_names() {
+        let dir = std::path::Path::new("baselines");
+        assert!(Baseline::path(dir, "main-2024.12").is_ok());
+        assert!(Baseline::path(dir, "../escape").is_err());
+        assert!(Baseline::path(dir, "").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use std::time::{Duration, Instant};
+
+use crate::days::{visual, Answer, Day, ParseError, Part};
+
+pub const DEFAULT_WARMUP: usize = 1;
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Expected at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartBench {
+    pub part: Part,
+    pub answer: Answer,
+    pub stats: Stats,
+}
+
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: usize,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+impl DayBench {
+    /// Sum of the median parse time and the median of every benchmarked part.
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.parts.iter().map(|p| p.stats.median).sum::<Duration>()
+    }
+
+    pub fn part(&self, part: Part) -> Option<&PartBench> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+}
+
+/// Runs `f` `warmup` times without measuring it, then `iterations` times measuring
+/// each call. Returns the samples and the result of the last call.
+fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> (Vec<Duration>, T) {
+    for _ in 0..warmup {
+        f();
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let r = f();
+        samples.push(start.elapsed());
+        result = Some(r);
+    }
+
+    (samples, result.expect("Expected at least one iteration"))
+}
+
+/// Times parsing and each of `parts` separately. Parts are solved against the input
+/// parsed by the last measured parse. Fails if the input does not parse. When
+/// visualizing, each part is solved once more before it is timed, so that it is
+/// drawn or exported once and the timings leave drawing out.
+pub fn bench_day(
+    day_num: usize,
+    day: &dyn Day,
+    input: &str,
+    parts: &[Part],
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    let (samples, parsed) = visual::paused(|| measure(warmup, iterations, || day.parse(input)));
+    let parsed = parsed?;
+    let parse = Stats::from_samples(&samples);
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            if visual::enabled() {
+                parsed.solve(part);
+            }
+            let (samples, answer) =
+                visual::paused(|| measure(warmup, iterations, || parsed.solve(part)));
+            PartBench {
+                part,
+                answer,
+                stats: Stats::from_samples(&samples),
+            }
+        })
+        .collect();
+
+    Ok(DayBench {
+        day: day_num,
+        parse,
+        parts,
+    })
+}
+
+pub fn print_day(bench: &DayBench) {
+    println!(
+        "Day {:<8} {:>12} {:>12} {:>12} {:>12}",
+        bench.day, "min", "median", "mean", "stddev"
+    );
+    print_row("  parse", &bench.parse);
+    for p in &bench.parts {
+        print_row(&format!("  part {}", p.part.number()), &p.stats);
+    }
+    for p in &bench.parts {
+        println!("  Part {}: {}", p.part.number(), p.answer);
+    }
+}
+
+fn print_row(label: &str, stats: &Stats) {
+    println!(
+        "{:<12} {:>12.2?} {:>12.2?} {:>12.2?} {:>12.2?}",
+        label, stats.min, stats.median, stats.mean, stats.stddev
+    );
+}
+
+/// Prints the median times of every benchmarked day, one row per day.
+pub fn print_summary(benches: &[DayBench]) {
+    println!(
+        "{:<6} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "parse", "part 1", "part 2", "total"
+    );
+
+    let part_median = |bench: &DayBench, part| {
+        bench
+            .part(part)
+            .map(|p| format!("{:.2?}", p.stats.median))
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    for bench in benches {
+        println!(
+            "{:<6} {:>12.2?} {:>12} {:>12} {:>12.2?}",
+            bench.day,
+            bench.parse.median,
+            part_median(bench, Part::One),
+            part_median(bench, Part::Two),
+            bench.total()
+        );
+    }
+
+    let total = benches.iter().map(DayBench::total).sum::<Duration>();
+    println!(
+        "{:<6} {:>12} {:>12} {:>12} {:>12.2?}",
+        "all", "", "", "", total
+    );
+}
+
+#[cfg(test)]
+mod bench_test {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2_236_068));
+
+        let stats = Stats::from_samples(&[ms(3)]);
+        assert_eq!(stats.min, ms(3));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
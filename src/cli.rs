@@ -0,0 +1,488 @@
+use std::{ops::RangeInclusive, time::Duration};
+
+use crate::baseline;
+use crate::bench::{DEFAULT_ITERATIONS, DEFAULT_WARMUP};
+use crate::days::{visual::DEFAULT_DELAY, Part};
+use crate::report::Format;
+use crate::scaffold::MAX_DAY;
+
+pub const USAGE: &str = "Usage: {program} [command] [options] [<day>] [<input>]
+
+Commands:
+    run      Solve the selected days (default)
+    bench    Time parsing and each part of the selected days
+    check    Solve the selected days and compare them against the known answers
+    list     List the registered days
+    fetch    Download the inputs and examples of the selected days
+    new      Add a day from the template, with empty input, answers and example files,
+             run from the crate root
+    submit   Submit the answer to one part of a day, or the computed one without --answer
+    explore  Step through a day interactively, reading commands from stdin (days 14, 17)
+    help     Show this message
+
+Options:
+    -d, --day <days>     Days to select: 5, 1..10, 1..=10, 1,3,7 or all
+    -y, --year <year>    Event the days belong to (default: the latest one)
+    -p, --part <part>    Only solve part 1 or 2
+    -i, --input <path>   Input file, or - to read from stdin
+    -f, --format <fmt>   Output format: text (default), json or csv
+    -j, --jobs <n>       Days to run or check at once (default: 1)
+        --warmup <n>     Untimed runs before benchmarking (default: 1)
+        --iterations <n> Timed runs when benchmarking (default: 10)
+        --save-baseline <name>
+                         Save the benchmark medians as baselines/<name>.csv
+        --baseline <name>
+                         Compare the benchmark against a saved baseline
+        --threshold <%>  Slowdown counted as a regression (default: 10)
+        --baselines <dir>
+                         Directory holding the baselines (default: baselines)
+    -e, --example        Use dayNN.example.txt instead of dayNN.txt
+        --answer <value> Answer to submit instead of solving the day
+        --visualize      Draw the grids some days work on, or trace day 17, on stderr
+        --delay <ms>     Pause between animation frames (default: 50)
+        --export <path>  Save what is drawn as a .png or .ppm per picture, or a .gif
+        --inputs <dir>   Directory holding the inputs (default: $AOC_INPUTS or inputs)
+        --answers <dir>  Directory holding the answers (default: $AOC_ANSWERS or answers)
+
+Without --input, inputs are read from <dir>/<year>/dayNN.txt, or for 2024 also from
+<dir>/dayNN.txt.
+Answers are read from dayNN.toml files in the same layout, with part1 and part2 keys.
+Fetching needs a session token, from $AOC_SESSION or the session key of aoc.toml (or
+the file named by $AOC_CONFIG), and saves to <dir>/<year>/ unless a file is there.
+Submitted answers are recorded in <answers dir>/<year>/submissions.csv, and answers
+it rules out aren't submitted.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    Check,
+    List,
+    Fetch,
+    Submit,
+    New,
+    Explore,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    pub year: u32,
+    pub days: Vec<usize>,
+    pub parts: Vec<Part>,
+    pub input: Option<String>,
+    pub inputs_dir: Option<String>,
+    pub answers_dir: Option<String>,
+    pub example: bool,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub format: Format,
+    pub jobs: usize,
+    pub baselines_dir: String,
+    pub save_baseline: Option<String>,
+    pub baseline: Option<String>,
+    pub threshold: f64,
+    pub visualize: bool,
+    pub delay: Duration,
+    pub export: Option<String>,
+    pub answer: Option<String>,
+}
+
+impl Args {
+    /// Parses the arguments following the program name. `years` holds each registered
+    /// event with its number of days, used to pick the latest event by default, to
+    /// expand `all` and to reject days that don't exist.
+    pub fn parse<I: IntoIterator<Item = String>>(
+        args: I,
+        years: &[(u32, usize)],
+    ) -> Result<Self, String> {
+        let mut args = args.into_iter().peekable();
+
+        let mut command = match args.peek().map(String::as_str) {
+            Some("run") => Command::Run,
+            Some("bench") => Command::Bench,
+            Some("check") => Command::Check,
+            Some("list") => Command::List,
+            Some("fetch") => Command::Fetch,
+            Some("submit") => Command::Submit,
+            Some("new") => Command::New,
+            Some("explore") => Command::Explore,
+            Some("help" | "-h" | "--help") => Command::Help,
+            _ => Command::Run,
+        };
+        if command != Command::Run || args.peek().is_some_and(|a| a == "run") {
+            args.next();
+        }
+
+        let mut year = None;
+        let mut days = None;
+        let mut parts = Part::ALL.to_vec();
+        let mut input = None;
+        let mut inputs_dir = None;
+        let mut answers_dir = None;
+        let mut example = false;
+        let mut warmup = DEFAULT_WARMUP;
+        let mut iterations = DEFAULT_ITERATIONS;
+        let mut format = Format::Text;
+        let mut jobs = 1;
+        let mut baselines_dir = baseline::DEFAULT_DIR.to_string();
+        let mut save_baseline = None;
+        let mut baseline = None;
+        let mut threshold = baseline::DEFAULT_THRESHOLD;
+        let mut visualize = false;
+        let mut delay = DEFAULT_DELAY;
+        let mut export = None;
+        let mut answer = None;
+        let mut positional = vec![];
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-d" | "--day" => {
+                    days = Some(args.next().ok_or("--day requires a value")?);
+                }
+                "-y" | "--year" => {
+                    let value = args.next().ok_or("--year requires a value")?;
+                    year = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("invalid year '{value}'"))?,
+                    );
+                }
+                "-p" | "--part" => {
+                    let value = args.next().ok_or("--part requires a value")?;
+                    parts = vec![Part::parse(&value)?];
+                }
+                "-i" | "--input" => {
+                    input = Some(args.next().ok_or("--input requires a value")?);
+                }
+                "--inputs" => {
+                    inputs_dir = Some(args.next().ok_or("--inputs requires a value")?);
+                }
+                "--answers" => {
+                    answers_dir = Some(args.next().ok_or("--answers requires a value")?);
+                }
+                "-f" | "--format" => {
+                    let value = args.next().ok_or("--format requires a value")?;
+                    format = Format::parse(&value)?;
+                }
+                "-j" | "--jobs" => {
+                    let value = args.next().ok_or("--jobs requires a value")?;
+                    jobs = parse_count(&value)?;
+                    if jobs == 0 {
+                        return Err("--jobs must be at least 1".to_string());
+                    }
+                }
+                "--warmup" => {
+                    let value = args.next().ok_or("--warmup requires a value")?;
+                    warmup = parse_count(&value)?;
+                }
+                "--iterations" => {
+                    let value = args.next().ok_or("--iterations requires a value")?;
+                    iterations = parse_count(&value)?;
+                    if iterations == 0 {
+                        return Err("--iterations must be at least 1".to_string());
+                    }
+                }
+                "--baselines" => {
+                    baselines_dir = args.next().ok_or("--baselines requires a value")?;
+                }
+                "--save-baseline" => {
+                    save_baseline = Some(args.next().ok_or("--save-baseline requires a value")?);
+                }
+                "--baseline" => {
+                    baseline = Some(args.next().ok_or("--baseline requires a value")?);
+                }
+                "--threshold" => {
+                    let value = args.next().ok_or("--threshold requires a value")?;
+                    threshold = value
+                        .parse()
+                        .ok()
+                        .filter(|t: &f64| t.is_finite() && *t >= 0.0)
+                        .ok_or_else(|| format!("invalid threshold '{value}'"))?;
+                }
+                "--delay" => {
+                    let value = args.next().ok_or("--delay requires a value")?;
+                    delay = Duration::from_millis(parse_count(&value)? as u64);
+                }
+                "--export" => {
+                    export = Some(args.next().ok_or("--export requires a value")?);
+                }
+                "--answer" => {
+                    answer = Some(args.next().ok_or("--answer requires a value")?);
+                }
+                "-e" | "--example" => example = true,
+                "--visualize" => visualize = true,
+                "-h" | "--help" => {
+                    command = Command::Help;
+                    break;
+                }
+                "-" => positional.push(arg),
+                flag if flag.starts_with('-') => return Err(format!("unknown option '{flag}'")),
+                _ => positional.push(arg),
+            }
+        }
+
+        if command == Command::Help {
+            // Help only shows the usage, whatever else was selected.
+            days = None;
+            positional.clear();
+        }
+        let mut positional = positional.into_iter();
+        if days.is_none() {
+            days = positional.next();
+        }
+        if input.is_none() {
+            input = positional.next();
+        }
+        if let Some(extra) = positional.next() {
+            return Err(format!("unexpected argument '{extra}'"));
+        }
+
+        let year = match year {
+            Some(year) => year,
+            None => years
+                .iter()
+                .map(|&(year, _)| year)
+                .max()
+                .ok_or("no events are registered")?,
+        };
+        let registered = years.iter().find(|&&(y, _)| y == year).map(|&(_, n)| n);
+        // Fetching and adding days doesn't need them to be solved yet.
+        let available = match (command, registered) {
+            (Command::Fetch | Command::New, _) => MAX_DAY,
+            (_, Some(available)) => available,
+            (Command::Help, None) => 0,
+            (_, None) => return Err(format!("there are no solutions for {year}")),
+        };
+
+        let days = match (command, days) {
+            (_, Some(days)) => parse_days(&days, available)?,
+            (Command::Check | Command::List, None) => (1..=available).collect(),
+            (Command::Help, None) => vec![],
+            (_, None) => return Err("no day selected".to_string()),
+        };
+
+        if command == Command::Explore && days.len() > 1 {
+            return Err("only a single day can be explored".to_string());
+        }
+        if command == Command::New && days.len() > 1 {
+            return Err("only a single day can be added at a time".to_string());
+        }
+        if command == Command::Submit {
+            if days.len() > 1 || parts.len() > 1 {
+                return Err("submit needs a single day and --part".to_string());
+            }
+            if example {
+                return Err("example answers can't be submitted".to_string());
+            }
+        }
+        if input.is_some() && days.len() > 1 {
+            return Err("an input can only be given when a single day is selected".to_string());
+        }
+
+        Ok(Self {
+            command,
+            year,
+            days,
+            parts,
+            input,
+            inputs_dir,
+            answers_dir,
+            example,
+            warmup,
+            iterations,
+            format,
+            jobs,
+            baselines_dir,
+            save_baseline,
+            baseline,
+            threshold,
+            visualize,
+            delay,
+            export,
+            answer,
+        })
+    }
+}
+
+fn parse_count(s: &str) -> Result<usize, String> {
+    s.parse().map_err(|_| format!("invalid count '{s}'"))
+}
+
+/// Parses a day selection: `all`, a single day, an exclusive (`1..10`) or inclusive
+/// (`1..=10`) range, or a comma separated list of any of those.
+fn parse_days(s: &str, available: usize) -> Result<Vec<usize>, String> {
+    if s == "all" {
+        return Ok((1..=available).collect());
+    }
+
+    let mut days = vec![];
+    for item in s.split(',') {
+        for day in parse_range(item)? {
+            if day == 0 || day > available {
+                return Err(format!("day {day} is not implemented"));
+            }
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_range(s: &str) -> Result<RangeInclusive<usize>, String> {
+    let parse_day = |d: &str| {
+        d.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("invalid day '{d}'"))
+    };
+
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let (start, end) = (parse_day(start)?, parse_day(end)?);
+        if end == 0 {
+            return Err(format!("empty day range '{s}'"));
+        }
+        start..=end - 1
+    } else {
+        let day = parse_day(s)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("empty day range '{s}'"));
+    }
+    Ok(range)
+}
+
+#[cfg(test)]
+mod cli_test {
+    use std::time::Duration;
+
+    use super::{parse_days, Args, Command};
+    use crate::days::Part;
+    use crate::report::Format;
+
+    fn args(s: &str) -> Result<Args, String> {
+        Args::parse(
+            s.split_whitespace().map(String::from),
+            &[(2023, 5), (2024, 20)],
+        )
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(parse_days("5", 20), Ok(vec![5]));
+        assert_eq!(parse_days("1..4", 20), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("1..=4", 20), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(parse_days("3,1..=2,3", 20), Ok(vec![3, 1, 2]));
+        assert_eq!(parse_days("4..=4", 20), Ok(vec![4]));
+        assert_eq!(parse_days("all", 3), Ok(vec![1, 2, 3]));
+        assert!(parse_days("0", 20).is_err());
+        assert!(parse_days("21", 20).is_err());
+        assert!(parse_days("1..=21", 20).is_err());
+        assert!(parse_days("x", 20).is_err());
+        assert_eq!(
+            parse_days("5..3", 20),
+            Err("empty day range '5..3'".to_string())
+        );
+        assert!(parse_days("5..=3", 20).is_err());
+        assert!(parse_days("4..4", 20).is_err());
+        assert!(parse_days("1..0", 20).is_err());
+
+        let a = args("16 input.txt").unwrap();
+        assert_eq!(a.days, vec![16]);
+        assert_eq!(a.input.as_deref(), Some("input.txt"));
+        assert_eq!(args("all --inputs puzzles").unwrap().days.len(), 20);
+        assert_eq!(args("list").unwrap().days.len(), 20);
+        assert_eq!(args("check").unwrap().days.len(), 20);
+        assert_eq!(args("fetch 1..=3").unwrap().days, vec![1, 2, 3]);
+        assert!(args("run --day 5..3").is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(args("16").unwrap().parts, vec![Part::One, Part::Two]);
+        assert_eq!(
+            args("bench --day 1..=3 --part 2").unwrap().parts,
+            vec![Part::Two]
+        );
+        assert_eq!(args("run 1 -p 1").unwrap().parts, vec![Part::One]);
+        assert!(args("run 1 --part 3").is_err());
+    }
+
+    #[test]
+    fn test_options() {
+        let a = args("bench --day 1..=3").unwrap();
+        assert_eq!((a.warmup, a.iterations), (1, 10));
+        let a = args("bench 7 --warmup 0 --iterations 3").unwrap();
+        assert_eq!((a.warmup, a.iterations), (0, 3));
+        assert_eq!(args("7").unwrap().format, Format::Text);
+        assert_eq!(args("check -f json").unwrap().format, Format::Json);
+        assert_eq!(args("check -j 4").unwrap().jobs, 4);
+
+        let a = args("bench all --baseline main --threshold 5.5").unwrap();
+        assert_eq!(a.baseline.as_deref(), Some("main"));
+        assert_eq!(a.threshold, 5.5);
+
+        assert_eq!(args("run 3 -").unwrap().input.as_deref(), Some("-"));
+        let a = args("all --inputs puzzles -e").unwrap();
+        assert_eq!(a.inputs_dir.as_deref(), Some("puzzles"));
+        assert!(a.example);
+        assert!(!a.visualize);
+
+        let a = args("run 15 --visualize --delay 0").unwrap();
+        assert!(a.visualize);
+        assert_eq!(a.delay, Duration::ZERO);
+        let a = args("run 15 --export moves.gif").unwrap();
+        assert_eq!(a.export.as_deref(), Some("moves.gif"));
+
+        assert_eq!(args("7").unwrap().year, 2024);
+        let a = args("check --year 2023").unwrap();
+        assert_eq!((a.year, a.days.len()), (2023, 5));
+        assert_eq!(args("fetch 7 -y 2022").unwrap().year, 2022);
+    }
+
+    #[test]
+    fn test_subcommands() {
+        assert_eq!(args("16").unwrap().command, Command::Run);
+        assert_eq!(args("bench 1").unwrap().command, Command::Bench);
+        assert_eq!(args("explore 14").unwrap().command, Command::Explore);
+        assert_eq!(args("new 21").unwrap().days, vec![21]);
+        let a = args("submit 3 --part 2 --answer 4,6").unwrap();
+        assert_eq!(a.command, Command::Submit);
+        assert_eq!(a.answer.as_deref(), Some("4,6"));
+
+        let help = args("help").unwrap();
+        assert_eq!(help.command, Command::Help);
+        assert!(help.days.is_empty());
+        assert_eq!(args("run 5 --help").unwrap(), help);
+        assert_eq!(args("submit 1 2 3 -h").unwrap(), help);
+        assert_eq!(args("run 5 -j 2 -h").unwrap().jobs, 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(args("bench 7 --iterations 0").is_err());
+        assert!(args("7 --format yaml").is_err());
+        assert!(args("check --jobs 0").is_err());
+        assert!(args("bench all --threshold -1").is_err());
+        assert!(args("run 15 --delay soon").is_err());
+        assert!(args("fetch").is_err());
+        assert!(args("run 7 -y 2023").is_err());
+        assert!(args("run 7 -y 2022").is_err());
+        assert!(args("new 26").is_err());
+        assert!(args("new 21,22").is_err());
+        assert!(args("submit 3").is_err());
+        assert!(args("submit 3 -p 1 -e").is_err());
+        assert!(args("explore").is_err());
+        assert!(args("explore 14,15").is_err());
+        assert!(args("run").is_err());
+        assert!(args("run 1..=2 input.txt").is_err());
+        assert!(args("run 1 --frobnicate").is_err());
+        assert!(args("--day").is_err());
+    }
+}
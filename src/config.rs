@@ -0,0 +1,124 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::answers::parse_pairs;
+
+/// Environment variable pointing at the config file.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+pub const DEFAULT_FILE: &str = "aoc.toml";
+/// Environment variables overriding the config file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(3);
+
+/// Settings for talking to the puzzle site, read from an `aoc.toml` file such as:
+///
+/// ```toml
+/// session = "53616c746564..."
+/// base_url = "http://localhost:8080"  # defaults to the real site
+/// rate_limit_ms = 5000                # the least time between two requests
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub rate_limit: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            rate_limit: DEFAULT_RATE_LIMIT,
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        for (line, key, value) in parse_pairs(input)? {
+            match key {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "rate_limit_ms" => {
+                    let ms = value
+                        .parse()
+                        .map_err(|_| format!("line {line}: invalid rate limit `{value}`"))?;
+                    config.rate_limit = Duration::from_millis(ms);
+                }
+                key => return Err(format!("line {line}: unknown key `{key}`")),
+            }
+        }
+
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+        Ok(config)
+    }
+
+    /// Reads the file named by `AOC_CONFIG`, or `aoc.toml` if there is one, then lets
+    /// `AOC_SESSION` and `AOC_BASE_URL` override what it says.
+    pub fn from_env() -> Result<Self, String> {
+        let path = std::env::var_os(CONFIG_ENV).map(PathBuf::from);
+        let mut config = match path {
+            Some(path) => Self::read(&path)?,
+            None if Path::new(DEFAULT_FILE).is_file() => Self::read(Path::new(DEFAULT_FILE))?,
+            None => Self::default(),
+        };
+
+        if let Ok(session) = std::env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            config.base_url = base_url.trim_end_matches('/').to_string();
+        }
+
+        Ok(config)
+    }
+
+    fn read(path: &Path) -> Result<Self, String> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Self::parse(&contents).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The session token, or an error explaining where to put it.
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| {
+                format!("no session token: set {SESSION_ENV} or add `session = \"...\"` to {DEFAULT_FILE}")
+            })
+    }
+}
+
+#[cfg(test)]
+mod config_test {
+    use std::time::Duration;
+
+    use super::{Config, DEFAULT_BASE_URL};
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "session = \"abc\"\nbase_url = \"http://localhost:8080/\"\nrate_limit_ms = 0\n",
+        )
+        .unwrap();
+        assert_eq!(config.session(), Ok("abc"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.rate_limit, Duration::ZERO);
+
+        let config = Config::parse("# nothing yet\n").unwrap();
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert!(config.session().is_err());
+        assert!(Config::parse("rate_limit_ms = -1").is_err());
+        assert!(Config::parse("token = \"abc\"").is_err());
+    }
+}
@@ -0,0 +1,396 @@
+use std::{
+    fmt,
+    ops::{Add, Index, IndexMut, Mul, Sub},
+};
+
+use itertools::Itertools;
+
+use super::parse::{self, ParseError};
+
+/// A position on a [`Grid`]. `x` is the column and `y` the row, growing down.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, n: isize) -> Self {
+        Self::new(self.x * n, self.y * n)
+    }
+}
+
+/// Offsets to the eight points around a point, clockwise from up.
+pub const AROUND: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The arrow drawn for this direction: `^`, `>`, `v` or `<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, one per line of `input`, which must all have the
+    /// same length.
+    pub fn from_rows(input: &str, rows: Vec<Vec<T>>) -> parse::Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((y, row)) = rows.iter().find_position(|row| row.len() != width) {
+            let line = input.lines().nth(y).unwrap_or(&input[input.len()..]);
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected {width} columns, found {}", row.len()),
+            ));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character, see [`parse::grid`].
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> parse::Result<Self> {
+        Self::from_rows(input, parse::grid(input, cell)?)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn point(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point(i), cell))
+    }
+
+    /// The up to four points next to `p` that are on the grid.
+    pub fn neighbors(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| p.step(dir))
+            .filter(|&n| self.contains(n))
+    }
+
+    /// The up to eight points around `p`, diagonals included, that are on the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        AROUND
+            .into_iter()
+            .map(move |offset| p + offset)
+            .filter(|&n| self.contains(n))
+    }
+
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(f).map(|i| self.point(i))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Expected column {x} to be on the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Finds the single cell matching `f`, reporting a missing or repeated one as an
+    /// error in `input`, the text the grid was parsed from.
+    pub fn find_one(
+        &self,
+        input: &str,
+        what: &str,
+        f: impl Fn(&T) -> bool,
+    ) -> parse::Result<Point> {
+        let mut found = self.cells.iter().enumerate().filter(|(_, cell)| f(cell));
+
+        match (found.next(), found.next()) {
+            (Some((i, _)), None) => Ok(self.point(i)),
+            (None, _) => Err(ParseError::eof(input, format!("expected a {what}"))),
+            (Some(_), Some((i, _))) => {
+                let p = self.point(i);
+                let line = input.lines().nth(p.y as usize).unwrap_or_default();
+                let column = line
+                    .char_indices()
+                    .nth(p.x as usize)
+                    .map_or(line.len(), |(k, _)| k);
+                Err(ParseError::at(
+                    input,
+                    &line[column..],
+                    format!("expected a single {what}"),
+                ))
+            }
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("Expected {p} to be on the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("Expected {p} to be on the grid"))
+    }
+}
+
+/// Draws one character per cell, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_test {
+    use super::{Direction, Grid, Point};
+    use crate::days::parse::ParseError;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("ab#\nc#d\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_access() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'd');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.find(&'c'), Some(Point::new(0, 1)));
+        assert_eq!(grid.row(1), ['c', '#', 'd']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "#d");
+        let columns = grid.columns().map(String::from_iter).collect::<Vec<_>>();
+        assert_eq!(columns, ["ac", "b#", "#d"]);
+        assert_eq!(grid.to_string(), "ab#\nc#d\n");
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = Grid::from_rows("ab\ncd\n", vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap();
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+
+        let input = "ab\nc\n";
+        let error = Grid::from_rows(input, vec![vec!['a', 'b'], vec!['c']]).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::at(input, &input[3..], "expected 2 columns, found 1")
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let corner = grid.neighbors(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_find_one() {
+        let input = "#S#\nS.E\n";
+        let grid = Grid::parse(input, Some).unwrap();
+        assert_eq!(
+            grid.find_one(input, "end", |&c| c == 'E'),
+            Ok(Point::new(2, 1))
+        );
+        let e = grid.find_one(input, "start", |&c| c == 'S').unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert!(grid.find_one(input, "wall", |&c| c == 'W').is_err());
+    }
+
+    #[test]
+    fn test_directions() {
+        let p = Point::new(1, 1);
+        assert_eq!(p.step(Direction::Up), Point::new(1, 0));
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+    }
+}
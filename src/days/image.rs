@@ -0,0 +1,374 @@
+use std::{
+    collections::HashMap,
+    io::{self, Seek, SeekFrom, Write},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// An RGB picture, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws `width` x `height` cells of `scale` x `scale` pixels each.
+    pub fn from_cells(
+        width: usize,
+        height: usize,
+        scale: usize,
+        cell: impl Fn(usize, usize) -> Rgb,
+    ) -> Self {
+        let pixels = (0..height * scale)
+            .flat_map(|y| (0..width * scale).map(move |x| (x / scale, y / scale)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+
+        Self {
+            width: width * scale,
+            height: height * scale,
+            pixels,
+        }
+    }
+}
+
+/// Writes a binary PPM (P6).
+pub fn write_ppm(out: &mut impl Write, image: &Image) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", image.width, image.height)?;
+    let bytes = image
+        .pixels
+        .iter()
+        .flat_map(|&Rgb(r, g, b)| [r, g, b])
+        .collect::<Vec<_>>();
+    out.write_all(&bytes)
+}
+
+/// Writes an 8-bit RGB PNG. The pixel data is stored without compression, which
+/// keeps the encoder small at the cost of bigger files.
+pub fn write_png(out: &mut impl Write, image: &Image) -> io::Result<()> {
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    let mut header = vec![];
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    // Bit depth 8, truecolor, then the default compression, filter and interlace.
+    header.extend([8, 2, 0, 0, 0]);
+    write_chunk(out, b"IHDR", &header)?;
+
+    let mut raw = Vec::with_capacity((image.width * 3 + 1) * image.height);
+    for row in image.pixels.chunks(image.width.max(1)) {
+        raw.push(0);
+        raw.extend(row.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+    }
+    write_chunk(out, b"IDAT", &zlib_stored(&raw))?;
+
+    write_chunk(out, b"IEND", &[])
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Wraps `data` in a zlib stream made of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    out.extend(((b << 16) | a).to_be_bytes());
+
+    out
+}
+
+/// Writes an animated GIF that loops forever, one frame at a time. Frames may differ
+/// in size; the picture is as big as the largest one.
+pub struct GifWriter<W: Write + Seek> {
+    out: W,
+    width: u16,
+    height: u16,
+}
+
+impl<W: Write + Seek> GifWriter<W> {
+    pub fn new(mut out: W) -> io::Result<Self> {
+        out.write_all(b"GIF89a")?;
+        // The screen size is filled in by `finish`. No global color table.
+        out.write_all(&[0, 0, 0, 0, 0, 0, 0])?;
+        out.write_all(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        Ok(Self {
+            out,
+            width: 0,
+            height: 0,
+        })
+    }
+
+    /// Adds a frame shown for `delay` hundredths of a second. A frame can use at most
+    /// 256 colors.
+    pub fn frame(&mut self, image: &Image, delay: u16) -> io::Result<()> {
+        let (width, height) = match (u16::try_from(image.width), u16::try_from(image.height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => return Err(invalid("a GIF frame is at most 65535 pixels wide and high")),
+        };
+
+        let mut colors = vec![];
+        let mut index = HashMap::new();
+        let mut indices = Vec::with_capacity(image.pixels.len());
+        for &rgb in &image.pixels {
+            let i = *index.entry(rgb).or_insert_with(|| {
+                colors.push(rgb);
+                colors.len() - 1
+            });
+            if i > 255 {
+                return Err(invalid("a GIF frame can use at most 256 colors"));
+            }
+            indices.push(i as u8);
+        }
+
+        // The color table holds 2^bits entries, with at least 2 bits for the LZW codes.
+        let bits = (2..=8).find(|&b| colors.len() <= 1 << b).unwrap();
+        colors.resize(1 << bits, Rgb(0, 0, 0));
+
+        let [d0, d1] = delay.to_le_bytes();
+        self.out.write_all(&[0x21, 0xF9, 4, 0, d0, d1, 0, 0])?;
+
+        self.out.write_all(&[0x2C, 0, 0, 0, 0])?;
+        self.out.write_all(&width.to_le_bytes())?;
+        self.out.write_all(&height.to_le_bytes())?;
+        self.out.write_all(&[0x80 | (bits - 1)])?;
+        for &Rgb(r, g, b) in &colors {
+            self.out.write_all(&[r, g, b])?;
+        }
+
+        self.out.write_all(&[bits])?;
+        for block in lzw(bits, &indices).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])?;
+
+        self.width = self.width.max(width);
+        self.height = self.height.max(height);
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3B])?;
+        self.out.seek(SeekFrom::Start(6))?;
+        self.out.write_all(&self.width.to_le_bytes())?;
+        self.out.write_all(&self.height.to_le_bytes())?;
+        self.out.seek(SeekFrom::End(0))?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Packs codes of varying width into bytes, least significant bit first.
+#[derive(Default)]
+struct Bits {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u8,
+}
+
+impl Bits {
+    fn push(&mut self, code: u16, width: u8) {
+        self.buffer |= (code as u32) << self.count;
+        self.count += width;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// GIF's variable width LZW compression of color indices below `2^min_size`.
+fn lzw(min_size: u8, indices: &[u8]) -> Vec<u8> {
+    const MAX_CODES: u16 = 4096;
+    let clear = 1u16 << min_size;
+    let end = clear + 1;
+
+    let mut out = Bits::default();
+    let mut width = min_size + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    out.push(clear, width);
+
+    let mut indices = indices.iter().copied();
+    let Some(first) = indices.next() else {
+        out.push(end, width);
+        return out.finish();
+    };
+
+    let mut prefix = first as u16;
+    for k in indices {
+        if let Some(&code) = table.get(&(prefix, k)) {
+            prefix = code;
+            continue;
+        }
+
+        out.push(prefix, width);
+        if next == MAX_CODES {
+            out.push(clear, width);
+            table.clear();
+            next = end + 1;
+            width = min_size + 1;
+        } else {
+            table.insert((prefix, k), next);
+            next += 1;
+            // The decoder learns each code one step later, hence `>` rather than `>=`.
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        }
+        prefix = k as u16;
+    }
+
+    out.push(prefix, width);
+    out.push(end, width);
+    out.finish()
+}
+
+#[cfg(test)]
+mod image_test {
+    use std::io::Cursor;
+
+    use super::{crc32, lzw, write_png, write_ppm, GifWriter, Image, Rgb};
+
+    /// A plain GIF LZW decoder, to check the encoder against.
+    fn unlzw(min_size: u8, bytes: &[u8]) -> Vec<u8> {
+        let clear = 1usize << min_size;
+        let mut width = min_size as usize + 1;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([vec![], vec![]]);
+        };
+        reset(&mut table);
+
+        let (mut out, mut prev): (Vec<u8>, Option<Vec<u8>>) = (vec![], None);
+        let mut bit = 0;
+        while bit + width <= bytes.len() * 8 {
+            let code = (0..width)
+                .map(|i| (((bytes[(bit + i) / 8] >> ((bit + i) % 8)) & 1) as usize) << i)
+                .sum::<usize>();
+            bit += width;
+
+            if code == clear {
+                reset(&mut table);
+                width = min_size as usize + 1;
+                prev = None;
+                continue;
+            }
+            if code == clear + 1 {
+                break;
+            }
+
+            let entry = match (table.get(code), &prev) {
+                (Some(e), _) => e.clone(),
+                (None, Some(p)) => [p.clone(), vec![p[0]]].concat(),
+                (None, None) => panic!("Expected a known first code"),
+            };
+            if let Some(p) = prev {
+                table.push([p, vec![entry[0]]].concat());
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            out.extend(&entry);
+            prev = Some(entry);
+        }
+
+        out
+    }
+
+    #[test]
+    fn test_lzw() {
+        let runs = (0..20_000).map(|i| ((i / 7) % 5) as u8).collect::<Vec<_>>();
+        let noise = (0..20_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect::<Vec<_>>();
+
+        assert_eq!(unlzw(3, &lzw(3, &runs)), runs);
+        assert_eq!(unlzw(8, &lzw(8, &noise)), noise);
+        assert_eq!(unlzw(2, &lzw(2, &[])), []);
+    }
+
+    #[test]
+    fn test_files() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+
+        let image = Image::from_cells(2, 1, 2, |x, _| Rgb(x as u8 * 255, 0, 0));
+        assert_eq!(
+            image.pixels[..4],
+            [Rgb(0, 0, 0), Rgb(0, 0, 0), Rgb(255, 0, 0), Rgb(255, 0, 0)]
+        );
+
+        let mut ppm = vec![];
+        write_ppm(&mut ppm, &image).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+
+        let mut png = vec![];
+        write_png(&mut png, &image).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR"));
+        assert!(png.ends_with(b"IEND\xAE\x42\x60\x82"));
+
+        let mut gif = GifWriter::new(Cursor::new(vec![])).unwrap();
+        gif.frame(&image, 5).unwrap();
+        gif.frame(&Image::from_cells(3, 3, 2, |_, _| Rgb(1, 2, 3)), 5)
+            .unwrap();
+        let gif = gif.finish().unwrap().into_inner();
+        assert_eq!(gif[..10], *b"GIF89a\x06\x00\x06\x00");
+        assert_eq!(gif.last(), Some(&0x3B));
+    }
+}
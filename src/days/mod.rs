@@ -1,44 +1,180 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-
-type Day = fn(input: String) -> ();
-pub const DAYS: &[Day] = &[
-    day1::day1,
-    day2::day2,
-    day3::day3,
-    day4::day4,
-    day5::day5,
-    day6::day6,
-    day7::day7,
-    day8::day8,
-    day9::day9,
-    day10::day10,
-    day11::day11,
-    day12::day12,
-    day13::day13,
-    day14::day14,
-    day15::day15,
-    day16::day16,
-    day17::day17,
-    day18::day18,
-    day19::day19,
-    day20::day20,
-];
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+pub mod grid;
+pub mod image;
+pub mod parallel;
+pub mod parse;
+#[cfg(test)]
+pub mod prop;
+pub mod search;
+pub mod visual;
+mod y2024;
+
+pub use parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{s}', expected 1 or 2")),
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The result of solving one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    Unsolved,
+    /// The input can't be solved, for the given reason, such as a program that never
+    /// halts.
+    Failed(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Failed(reason) => write!(f, "failed, {reason}"),
+        }
+    }
+}
+
+/// Counts too large for an `i64` are kept exact as text.
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Int(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A day's solution: parse the puzzle input once, then answer both parts from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Answer;
+    fn part2(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// Steps through the puzzle interactively, reading one command per line. `None`
+    /// for days without an explore mode.
+    fn explore(&self, _input: &Self::Input, _commands: &mut dyn BufRead) -> Option<io::Result<()>> {
+        None
+    }
+}
+
+/// Type-erased view of a [`Solution`], so that every day can live in a [`Year`].
+pub trait Day: Sync {
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+}
+
+/// A parsed puzzle input, ready to have its parts solved.
+pub trait Parsed {
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+    fn explore(&self, commands: &mut dyn BufRead) -> Option<io::Result<()>>;
+
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+struct Prepared<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for Prepared<'_, S> {
+    fn part1(&self) -> Answer {
+        self.solution.part1(&self.input)
+    }
+
+    fn part2(&self) -> Answer {
+        self.solution.part2(&self.input)
+    }
+
+    fn explore(&self, commands: &mut dyn BufRead) -> Option<io::Result<()>> {
+        self.solution.explore(&self.input, commands)
+    }
+}
+
+impl<S: Solution + Sync> Day for S {
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(Prepared {
+            solution: self,
+            input: Solution::parse(self, input)?,
+        }))
+    }
+}
+
+/// The days of one event, in order, so that day `n` is `days[n - 1]`.
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [&'static dyn Day],
+}
+
+/// Every registered event, oldest first. Each has its own module, like `y2024`.
+pub const YEARS: &[Year] = &[y2024::YEAR];
+
+pub fn year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The solution for `day` of the `year` event, if there is one.
+pub fn day(year: u32, day: usize) -> Option<&'static dyn Day> {
+    let days = self::year(year)?.days;
+    day.checked_sub(1).and_then(|i| days.get(i)).copied()
+}
+
+#[cfg(test)]
+mod days_test {
+    use super::Answer;
+
+    #[test]
+    fn test_answer_from_usize() {
+        assert_eq!(Answer::from(42_usize), Answer::Int(42));
+        assert_eq!(Answer::from(i64::MAX as usize), Answer::Int(i64::MAX));
+        assert_eq!(
+            Answer::from(usize::MAX),
+            Answer::Text(usize::MAX.to_string())
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::{
+    cell::Cell,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+thread_local! {
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The number of threads to use by default: one per available core.
+pub fn threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Applies `f` to every item on one thread per core, see [`map_on`].
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_on(threads(), items, f)
+}
+
+/// Applies `f` to every item using up to `threads` threads, returning the results in
+/// the order of `items`. Each thread takes the next unclaimed item as soon as it is
+/// done with its last one, so uneven items still keep every thread busy. Calls made
+/// from inside a worker run on the calling thread, so nesting doesn't multiply threads.
+pub fn map_on<T: Sync, R: Send>(threads: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = threads.min(items.len());
+    if threads <= 1 || IN_WORKER.get() {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                IN_WORKER.set(true);
+                let mut done = vec![];
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    done.push((i, f(item)));
+                }
+                results.lock().unwrap().extend(done);
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+#[cfg(test)]
+mod parallel_test {
+    use super::{map, map_on};
+
+    #[test]
+    fn test_order() {
+        let items = (0..1000u64).collect::<Vec<_>>();
+        let squares = items.iter().map(|n| n * n).collect::<Vec<_>>();
+        assert_eq!(map_on(4, &items, |n| n * n), squares);
+        assert_eq!(map_on(1, &items, |n| n * n), squares);
+        assert_eq!(map_on(64, &items[..3], |n| n * n), squares[..3]);
+        assert_eq!(map(&[] as &[u64], |n| n * n), []);
+    }
+
+    #[test]
+    fn test_nested() {
+        let outer = (0..8).collect::<Vec<usize>>();
+        let sums = map_on(4, &outer, |&n| {
+            map_on(4, &(0..n).collect::<Vec<_>>(), |m| m + 1)
+        });
+        assert_eq!(sums[3], [1, 2, 3]);
+        assert_eq!(sums[0], []);
+    }
+}
@@ -0,0 +1,210 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A problem found in a puzzle input. Line and column are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error located at `part`, which must be a slice of `input`. Anything else is
+    /// reported at the end of the input.
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// An error at the very end of `input`, for things that are missing.
+    pub fn eof(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Parses `s`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T> {
+    s.parse().map_err(|_| {
+        if s.is_empty() {
+            ParseError::at(input, s, "expected a number")
+        } else {
+            ParseError::at(input, s, format!("expected a number, found `{s}`"))
+        }
+    })
+}
+
+/// Splits `s`, a slice of `input`, around the first `delimiter`.
+pub fn split_once<'a>(input: &str, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, s, format!("expected `{delimiter}`")))
+}
+
+/// Strips `prefix` from `s`, a slice of `input`.
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, s, format!("expected `{prefix}`")))
+}
+
+/// Splits off the first line of `input` when it is a `name: value` header, which
+/// inputs use for what the puzzle gives in its text rather than its input, such as the
+/// size of the grid in an example. Returns the value, if there was one, and the rest.
+pub fn header<'a>(input: &'a str, name: &str) -> (Option<&'a str>, &'a str) {
+    match input
+        .strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix(": "))
+    {
+        Some(rest) => {
+            let (value, rest) = rest.split_once('\n').unwrap_or((rest, ""));
+            (Some(value), rest)
+        }
+        None => (None, input),
+    }
+}
+
+/// Parses `s`, a slice of `input`, as a `<width>x<height>` size.
+pub fn size(input: &str, s: &str) -> Result<(usize, usize)> {
+    let (width, height) = split_once(input, s, "x")?;
+    let size = (number(input, width)?, number(input, height)?);
+    if size.0 == 0 || size.1 == 0 {
+        return Err(ParseError::at(
+            input,
+            s,
+            format!("expected a size, found `{s}`"),
+        ));
+    }
+    Ok(size)
+}
+
+/// Splits `input` into the sections separated by a blank line, expecting exactly `N`.
+pub fn sections<const N: usize>(input: &str) -> Result<[&str; N]> {
+    let mut sections = input.split("\n\n");
+    let mut found = [""; N];
+    for (i, section) in found.iter_mut().enumerate() {
+        *section = sections
+            .next()
+            .ok_or_else(|| ParseError::eof(input, format!("expected {N} sections, found {i}")))?;
+    }
+
+    match sections.find(|s| !s.trim().is_empty()) {
+        Some(extra) => Err(ParseError::at(
+            input,
+            extra,
+            format!("expected {N} sections separated by a blank line"),
+        )),
+        None => Ok(found),
+    }
+}
+
+/// Parses a non-empty rectangular grid, mapping each character with `cell`, which
+/// returns `None` for characters that are not allowed. Errors are located relative to
+/// `input`, so a grid section must come first in the puzzle input.
+pub fn grid<T>(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Vec<Vec<T>>> {
+    let mut rows: Vec<Vec<T>> = vec![];
+    for line in input.lines() {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| {
+                cell(c).ok_or_else(|| {
+                    ParseError::at(input, &line[i..], format!("unexpected character `{c}`"))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected {} columns, found {}", first.len(), row.len()),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+
+    if rows.first().is_none_or(Vec::is_empty) {
+        return Err(ParseError::eof(input, "expected a grid"));
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod parse_test {
+    use super::{grid, header, number, sections, size, ParseError};
+
+    #[test]
+    fn test_location() {
+        let input = "12 34\n56 x8\n";
+        let e = number::<usize>(input, &input[9..11]).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 4: expected a number, found `x8`"
+        );
+
+        let e = ParseError::eof(input, "expected more");
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(grid("12\n34\n", digit), Ok(vec![vec![1, 2], vec![3, 4]]));
+
+        let e = grid("12\n3x\n", digit).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        let e = grid("12\n345\n", digit).unwrap_err();
+        assert_eq!(e.message, "expected 2 columns, found 3");
+        assert!(grid("", digit).is_err());
+    }
+
+    #[test]
+    fn test_header() {
+        assert_eq!(header("Size: 7x7\n1,2\n", "Size"), (Some("7x7"), "1,2\n"));
+        assert_eq!(header("Size: 7x7", "Size"), (Some("7x7"), ""));
+        assert_eq!(header("1,2\n", "Size"), (None, "1,2\n"));
+        assert_eq!(header("Sizes: 7x7\n", "Size"), (None, "Sizes: 7x7\n"));
+
+        let input = "Size: 11x7\n";
+        assert_eq!(size(input, &input[6..10]), Ok((11, 7)));
+        let e = size(input, &input[6..9]).unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (1, 10, "expected a number")
+        );
+        assert!(size("0x3", "0x3").is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(sections::<2>("a\nb\n\nc\n"), Ok(["a\nb", "c\n"]));
+        assert!(sections::<2>("a\nb\n").is_err());
+        assert!(sections::<2>("a\n\nb\n\nc").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    fmt::Debug,
+    ops::{Range, RangeInclusive},
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Environment variables to replay a failure with the seed it reports, or to try
+/// more cases.
+pub const SEED_ENV: &str = "PROP_SEED";
+pub const CASES_ENV: &str = "PROP_CASES";
+
+const DEFAULT_CASES: usize = 200;
+
+/// A small deterministic random number generator (SplitMix64), enough to generate
+/// puzzle-shaped inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        start + (self.next_u64() % (end - start + 1) as u64) as usize
+    }
+
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    /// A vector with a length in `len`, each item made by `item`.
+    pub fn vec<T>(
+        &mut self,
+        len: RangeInclusive<usize>,
+        mut item: impl FnMut(&mut Self) -> T,
+    ) -> Vec<T> {
+        let len = self.range(len);
+        (0..len).map(|_| item(self)).collect()
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+/// Checks `property` against inputs made by `generate`, which should panic (with
+/// `assert!` and friends) when it doesn't hold. The first failing input is reported
+/// with the seed that makes it, so it can be replayed with `PROP_SEED`.
+pub fn check<T: Debug>(generate: impl Fn(&mut Rng) -> T, property: impl Fn(&T)) {
+    let env = |name| std::env::var(name).ok().and_then(|v| v.parse().ok());
+    let seeds = match env(SEED_ENV) {
+        Some(seed) => seed..seed + 1,
+        None => 0..env(CASES_ENV).unwrap_or(DEFAULT_CASES as u64),
+    };
+    check_seeds(seeds, generate, property);
+}
+
+fn check_seeds<T: Debug>(
+    seeds: Range<u64>,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T),
+) {
+    for seed in seeds {
+        let input = generate(&mut Rng::new(seed));
+        if panic::catch_unwind(AssertUnwindSafe(|| property(&input))).is_err() {
+            panic!("property failed with {SEED_ENV}={seed} for {input:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod prop_test {
+    use super::{check_seeds, Rng};
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+        }
+        assert_eq!(rng.vec(4..=4, |rng| rng.range(0..=0)), [0; 4]);
+
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert_eq!(Rng::new(1).next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    #[should_panic(expected = "property failed with PROP_SEED=")]
+    fn test_failure() {
+        check_seeds(0..100, |rng| rng.range(0..=9), |&n| assert!(n < 9));
+    }
+}
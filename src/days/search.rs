@@ -0,0 +1,289 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Everything a search from one start learned: the distance to every reached state
+/// and, for each state, the states before it on its shortest paths.
+#[derive(Debug)]
+pub struct Search<S> {
+    start: S,
+    distances: HashMap<S, usize>,
+    parents: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S) -> Self {
+        Self {
+            distances: HashMap::from([(start.clone(), 0)]),
+            start,
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Records reaching `next` from `from` with a total cost of `cost`. Returns whether
+    /// that is a new shortest distance to `next`.
+    fn relax(&mut self, from: &S, next: S, cost: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(&d) if cost > d => false,
+            Some(&d) if cost == d => {
+                if next != self.start {
+                    self.parents.entry(next).or_default().push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.parents.insert(next, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// The distance to every reached state.
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// One shortest path from the start to `goal`, both included.
+    pub fn path(&self, goal: &S) -> Option<Vec<S>> {
+        self.distance(goal)?;
+
+        let mut path = vec![goal.clone()];
+        while let Some(parents) = self.parents.get(path.last().unwrap()) {
+            path.push(parents[0].clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every shortest path from the start to `goal`, both included.
+    pub fn paths(&self, goal: &S) -> Vec<Vec<S>> {
+        if self.distance(goal).is_none() {
+            return vec![];
+        }
+
+        let mut paths = vec![];
+        let mut stack = vec![vec![goal.clone()]];
+        while let Some(path) = stack.pop() {
+            match self.parents.get(path.last().unwrap()) {
+                None => paths.push(path.into_iter().rev().collect()),
+                Some(parents) => stack.extend(parents.iter().map(|p| {
+                    let mut path = path.clone();
+                    path.push(p.clone());
+                    path
+                })),
+            }
+        }
+
+        paths
+    }
+
+    /// The number of shortest paths from the start to `goal`, without listing them.
+    pub fn count_paths(&self, goal: &S) -> usize {
+        fn count<S: Clone + Eq + Hash>(
+            search: &Search<S>,
+            state: &S,
+            memo: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(&n) = memo.get(state) {
+                return n;
+            }
+
+            let n = match search.parents.get(state) {
+                None => 1,
+                Some(parents) => parents.iter().map(|p| count(search, p, memo)).sum(),
+            };
+            memo.insert(state.clone(), n);
+            n
+        }
+
+        if self.distance(goal).is_none() {
+            return 0;
+        }
+        count(self, goal, &mut HashMap::new())
+    }
+
+    /// Every state on at least one shortest path to one of `goals`.
+    pub fn on_paths(&self, goals: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = goals
+            .into_iter()
+            .filter(|g| self.distances.contains_key(g))
+            .collect::<Vec<_>>();
+
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                if let Some(parents) = self.parents.get(&state) {
+                    stack.extend(parents.iter().cloned());
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+fn bfs_until<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> (Search<S>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, dist)) = queue.pop_front() {
+        if goal(&state) {
+            return (search, Some(state));
+        }
+
+        for next in neighbors(&state) {
+            if search.relax(&state, next.clone(), dist + 1) {
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+
+    (search, None)
+}
+
+/// Breadth-first search of everything reachable from `start`, every step costing 1.
+pub fn bfs<S, I>(start: S, neighbors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_until(start, neighbors, |_| false).0
+}
+
+/// A shortest path from `start` to the first state matching `goal`, both included.
+pub fn bfs_to<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (search, found) = bfs_until(start, neighbors, goal);
+    search.path(&found?)
+}
+
+/// Dijkstra's algorithm over everything reachable from `start`. `neighbors` returns
+/// each next state with the cost of the step to it.
+pub fn dijkstra<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if !settled.insert(state.clone()) {
+            continue;
+        }
+
+        for (next, step) in neighbors(&state) {
+            if search.relax(&state, next.clone(), cost + step) {
+                queue.push(Reverse((cost + step, next)));
+            }
+        }
+    }
+
+    search
+}
+
+/// A* from `start` to the first state matching `goal`. `heuristic` must never
+/// overestimate the remaining cost. Returns the cost and the path, both ends included.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((_, cost, state))) = queue.pop() {
+        if goal(&state) {
+            return Some((cost, search.path(&state)?));
+        }
+        if !settled.insert(state.clone()) {
+            continue;
+        }
+
+        for (next, step) in neighbors(&state) {
+            if search.relax(&state, next.clone(), cost + step) {
+                let estimate = cost + step + heuristic(&next);
+                queue.push(Reverse((estimate, cost + step, next)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod search_test {
+    use super::{astar, bfs, bfs_to, dijkstra};
+
+    // 0 -> 1 -> 3 and 0 -> 2 -> 3, then 3 -> 4.
+    fn edges(n: &u32) -> Vec<u32> {
+        match n {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            3 => vec![4],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(0, edges);
+        assert_eq!(search.distance(&4), Some(3));
+        assert_eq!(search.distance(&5), None);
+        assert_eq!(search.count_paths(&4), 2);
+        assert_eq!(search.paths(&4).len(), 2);
+        assert_eq!(search.on_paths([3]).len(), 4);
+
+        assert_eq!(bfs_to(0, edges, |&n| n == 3), Some(vec![0, 1, 3]));
+        assert_eq!(bfs_to(0, edges, |&n| n == 7), None);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // The direct edge costs more than going around.
+        let weighted = |n: &u32| match n {
+            0 => vec![(1, 1), (2, 10)],
+            1 => vec![(2, 2)],
+            _ => vec![],
+        };
+
+        let search = dijkstra(0, weighted);
+        assert_eq!(search.distance(&2), Some(3));
+        assert_eq!(search.path(&2), Some(vec![0, 1, 2]));
+
+        assert_eq!(
+            astar(0, weighted, |&n| 2 - n.min(2) as usize, |&n| n == 2),
+            Some((3, vec![0, 1, 2]))
+        );
+    }
+}
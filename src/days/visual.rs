@@ -0,0 +1,368 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, IsTerminal, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use super::{
+    grid::{Grid, Point},
+    image::{self, GifWriter, Image, Rgb},
+};
+
+/// The pause between animation frames unless `--delay` says otherwise.
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(50);
+
+/// The size in pixels of one grid cell in exported images.
+const CELL_SIZE: usize = 4;
+
+static DRAWING: AtomicBool = AtomicBool::new(false);
+static EXPORTING: AtomicBool = AtomicBool::new(false);
+static DELAY_MS: AtomicU64 = AtomicU64::new(DEFAULT_DELAY.as_millis() as u64);
+static EXPORT: Mutex<Option<Export>> = Mutex::new(None);
+
+/// Turns drawing to the terminal on. Drawing is off unless `--visualize` is given.
+pub fn enable() {
+    DRAWING.store(true, Ordering::Relaxed);
+}
+
+/// Sets the pause after each animation frame, which is also how long exported
+/// animation frames are shown.
+pub fn set_delay(delay: Duration) {
+    DELAY_MS.store(delay.as_millis() as u64, Ordering::Relaxed);
+}
+
+/// Whether anything is drawn or exported, so days can skip building pictures otherwise.
+pub fn enabled() -> bool {
+    DRAWING.load(Ordering::Relaxed) || EXPORTING.load(Ordering::Relaxed)
+}
+
+/// Runs `f` with drawing and exporting turned off, such as while benchmarking, then
+/// turns back on whatever was on.
+pub fn paused<T>(f: impl FnOnce() -> T) -> T {
+    let drawing = DRAWING.swap(false, Ordering::Relaxed);
+    let exporting = EXPORTING.swap(false, Ordering::Relaxed);
+    let result = f();
+    DRAWING.store(drawing, Ordering::Relaxed);
+    EXPORTING.store(exporting, Ordering::Relaxed);
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Png,
+    Ppm,
+    Gif,
+}
+
+/// Where pictures go when exporting: every picture into one GIF, or numbered PNG or
+/// PPM files next to the given path.
+struct Export {
+    path: PathBuf,
+    format: ExportFormat,
+    gif: Option<GifWriter<BufWriter<File>>>,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl Export {
+    fn numbered(&self, n: usize) -> PathBuf {
+        let stem = self.path.file_stem().unwrap_or_default().to_string_lossy();
+        let ext = self.path.extension().unwrap_or_default().to_string_lossy();
+        self.path.with_file_name(format!("{stem}-{n:04}.{ext}"))
+    }
+
+    fn write(&mut self, image: &Image, delay: Duration) -> io::Result<()> {
+        self.written += 1;
+        match (self.format, &mut self.gif) {
+            (ExportFormat::Gif, Some(gif)) => {
+                let hundredths = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+                gif.frame(image, hundredths)
+            }
+            (format, _) => {
+                let mut out = BufWriter::new(File::create(self.numbered(self.written))?);
+                match format {
+                    ExportFormat::Ppm => image::write_ppm(&mut out, image)?,
+                    _ => image::write_png(&mut out, image)?,
+                }
+                out.flush()
+            }
+        }
+    }
+}
+
+/// Starts exporting every picture to `path`, as a `.png`, `.ppm` or animated `.gif`.
+pub fn export(path: &Path) -> io::Result<()> {
+    let format = match path.extension().and_then(|e| e.to_str()) {
+        Some("png") => ExportFormat::Png,
+        Some("ppm") => ExportFormat::Ppm,
+        Some("gif") => ExportFormat::Gif,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}: expected a .png, .ppm or .gif file", path.display()),
+            ))
+        }
+    };
+    let gif = match format {
+        ExportFormat::Gif => Some(GifWriter::new(BufWriter::new(File::create(path)?))?),
+        _ => None,
+    };
+
+    *EXPORT.lock().unwrap() = Some(Export {
+        path: path.to_path_buf(),
+        format,
+        gif,
+        written: 0,
+        error: None,
+    });
+    EXPORTING.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Completes the export, returning a description of what was written. A single PNG
+/// or PPM picture is written to the path itself rather than a numbered file.
+pub fn finish_export() -> io::Result<Option<String>> {
+    EXPORTING.store(false, Ordering::Relaxed);
+    let Some(mut export) = EXPORT.lock().unwrap().take() else {
+        return Ok(None);
+    };
+    if let Some(e) = export.error.take() {
+        return Err(e);
+    }
+
+    if let Some(gif) = export.gif.take() {
+        gif.finish()?;
+    } else if export.written == 1 {
+        std::fs::rename(export.numbered(1), &export.path)?;
+    }
+
+    let target = match (export.format, export.written) {
+        (ExportFormat::Gif, _) | (_, 1) => export.path.display().to_string(),
+        _ => format!(
+            "{} through {}",
+            export.numbered(1).display(),
+            export.numbered(export.written).display()
+        ),
+    };
+    Ok(Some(format!(
+        "Exported {} picture(s) to {target}",
+        export.written
+    )))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn ansi(self) -> String {
+        match self {
+            Color::Red => "31".to_string(),
+            Color::Green => "32".to_string(),
+            Color::Yellow => "33".to_string(),
+            Color::Blue => "34".to_string(),
+            Color::Gray => "90".to_string(),
+            Color::Rgb(r, g, b) => format!("38;2;{r};{g};{b}"),
+        }
+    }
+
+    fn rgb(self) -> Rgb {
+        match self {
+            Color::Red => Rgb(220, 50, 47),
+            Color::Green => Rgb(80, 200, 80),
+            Color::Yellow => Rgb(230, 200, 40),
+            Color::Blue => Rgb(60, 120, 220),
+            Color::Gray => Rgb(110, 110, 110),
+            Color::Rgb(r, g, b) => Rgb(r, g, b),
+        }
+    }
+}
+
+/// A grid of characters to draw, each with an optional color.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    cells: Grid<(char, Option<Color>)>,
+}
+
+impl Canvas {
+    pub fn new(grid: &Grid<char>) -> Self {
+        Self {
+            cells: grid.map(|&c| (c, None)),
+        }
+    }
+
+    /// Draws each cell of `grid` with its `Display` implementation, which should be a
+    /// single character.
+    pub fn from_display<T: fmt::Display>(grid: &Grid<T>) -> Self {
+        Self {
+            cells: grid.map(|cell| (cell.to_string().chars().next().unwrap_or(' '), None)),
+        }
+    }
+
+    /// Colors the cells drawn with each character of `palette`, like the walls of a maze.
+    pub fn palette(&mut self, palette: &[(char, Color)]) -> &mut Self {
+        for p in self.cells.points().collect::<Vec<_>>() {
+            let cell = &mut self.cells[p];
+            if let Some(&(_, color)) = palette.iter().find(|&&(c, _)| c == cell.0) {
+                cell.1 = Some(color);
+            }
+        }
+        self
+    }
+
+    /// Colors `points` without changing what is drawn there, e.g. a visited set.
+    pub fn highlight(
+        &mut self,
+        points: impl IntoIterator<Item = Point>,
+        color: Color,
+    ) -> &mut Self {
+        for p in points {
+            if let Some(cell) = self.cells.get_mut(p) {
+                cell.1 = Some(color);
+            }
+        }
+        self
+    }
+
+    /// Draws `c` in `color` at each of `points`, e.g. a path.
+    pub fn overlay(
+        &mut self,
+        points: impl IntoIterator<Item = Point>,
+        c: char,
+        color: Color,
+    ) -> &mut Self {
+        for p in points {
+            self.set(p, c, color);
+        }
+        self
+    }
+
+    pub fn set(&mut self, p: Point, c: char, color: Color) -> &mut Self {
+        if let Some(cell) = self.cells.get_mut(p) {
+            *cell = (c, Some(color));
+        }
+        self
+    }
+
+    /// The canvas as text, one line per row, with ANSI colors if `colored`.
+    pub fn render(&self, colored: bool) -> String {
+        let mut out = String::new();
+        for row in self.cells.rows() {
+            for &(c, color) in row {
+                match color {
+                    Some(color) if colored => {
+                        out.push_str(&format!("\x1B[{}m{c}\x1B[0m", color.ansi()))
+                    }
+                    _ => out.push(c),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The canvas as a picture. Cells without a color are dark if empty and light
+    /// otherwise.
+    pub fn to_image(&self, scale: usize) -> Image {
+        Image::from_cells(
+            self.cells.width(),
+            self.cells.height(),
+            scale,
+            |x, y| match self.cells[Point::new(x as isize, y as isize)] {
+                (_, Some(color)) => color.rgb(),
+                (' ' | '.', None) => Rgb(24, 24, 24),
+                (_, None) => Rgb(200, 200, 200),
+            },
+        )
+    }
+}
+
+fn draw(canvas: &Canvas, caption: &str, clear: bool) {
+    let mut out = io::stderr().lock();
+    let colored = out.is_terminal();
+    let clear = if clear && colored {
+        "\x1B[2J\x1B[H"
+    } else {
+        "\n"
+    };
+
+    // Drawing is best effort, a closed stderr is no reason to stop solving.
+    let _ = writeln!(out, "{clear}{}{caption}", canvas.render(colored));
+}
+
+fn export_picture(canvas: &Canvas, delay: Duration) {
+    let mut export = EXPORT.lock().unwrap();
+    if let Some(export) = export.as_mut().filter(|e| e.error.is_none()) {
+        if let Err(e) = export.write(&canvas.to_image(CELL_SIZE), delay) {
+            export.error = Some(e);
+        }
+    }
+}
+
+fn delay() -> Duration {
+    Duration::from_millis(DELAY_MS.load(Ordering::Relaxed))
+}
+
+/// Draws a single picture, if drawing is on. Exported animations hold it for at
+/// least a second.
+pub fn show(canvas: &Canvas, caption: &str) {
+    if DRAWING.load(Ordering::Relaxed) {
+        draw(canvas, caption, false);
+    }
+    if EXPORTING.load(Ordering::Relaxed) {
+        export_picture(canvas, delay().max(Duration::from_secs(1)));
+    }
+}
+
+/// Draws one frame of an animation over the previous one, then waits for the delay,
+/// if drawing is on.
+pub fn frame(canvas: &Canvas, caption: &str) {
+    if DRAWING.load(Ordering::Relaxed) {
+        draw(canvas, caption, true);
+        thread::sleep(delay());
+    }
+    if EXPORTING.load(Ordering::Relaxed) {
+        export_picture(canvas, delay());
+    }
+}
+
+#[cfg(test)]
+mod visual_test {
+    use super::{Canvas, Color};
+    use crate::days::{
+        grid::{Grid, Point},
+        image::Rgb,
+    };
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::parse("#..\n..#\n", Some).unwrap();
+        let mut canvas = Canvas::new(&grid);
+        canvas
+            .overlay([Point::new(1, 0), Point::new(1, 1)], 'O', Color::Green)
+            .highlight([Point::new(2, 1), Point::new(5, 5)], Color::Red);
+
+        assert_eq!(canvas.render(false), "#O.\n.O#\n");
+        let image = canvas.to_image(1);
+        assert_eq!(image.pixels[0], Rgb(200, 200, 200));
+        assert_eq!(image.pixels[1], Color::Green.rgb());
+        assert_eq!(image.pixels[2], Rgb(24, 24, 24));
+        assert_eq!(
+            canvas.render(true),
+            "#\x1B[32mO\x1B[0m.\n.\x1B[32mO\x1B[0m\x1B[31m#\x1B[0m\n"
+        );
+    }
+}
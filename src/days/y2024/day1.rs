@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use crate::days::{parse, Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        let (mut left, mut right) = (vec![], vec![]);
+        for line in input.lines() {
+            let (l, r) = parse::split_once(input, line, "   ")?;
+            left.push(parse::number(input, l)?);
+            right.push(parse::number(input, r.trim_start())?);
+        }
+
+        Ok((left, right))
+    }
+
+    fn part1(&self, (left, right): &Self::Input) -> Answer {
+        part1(left, right).into()
+    }
+
+    fn part2(&self, (left, right): &Self::Input) -> Answer {
+        part2(left, right).into()
+    }
+}
+
+fn part1(left: &[usize], right: &[usize]) -> usize {
+    let mut left = left.to_vec();
+    left.sort();
+    let mut right = right.to_vec();
+    right.sort();
+
+    left.iter()
+        .zip(right.iter())
+        .fold(0, |acc, (l, r)| acc + l.abs_diff(*r))
+}
+
+fn part2(left: &[usize], right: &[usize]) -> usize {
+    let right_vec = VectorizedList::new(right);
+    right_vec.similarity(left)
+}
+
+struct VectorizedList(HashMap<usize, usize>);
+
+impl VectorizedList {
+    fn similarity(&self, other: &[usize]) -> usize {
+        other
+            .iter()
+            .map(|n| {
+                let freq = self.0.get(n).unwrap_or(&0);
+                n * freq
+            })
+            .sum()
+    }
+
+    fn new(l: &[usize]) -> VectorizedList {
+        let mut vec = VectorizedList(HashMap::new());
+        l.iter().for_each(|n| {
+            vec.0.entry(*n).and_modify(|c| *c += 1).or_insert(1);
+        });
+
+        vec
+    }
+}
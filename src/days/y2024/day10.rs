@@ -0,0 +1,49 @@
+use crate::days::{
+    grid::{Grid, Point},
+    parse, search, Answer, Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        let (p1, _) = solve(grid);
+        p1.into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        let (_, p2) = solve(grid);
+        p2.into()
+    }
+}
+
+fn solve(grid: &Grid<u8>) -> (usize, usize) {
+    grid.iter()
+        .filter(|&(_, &level)| level == 0)
+        .fold((0, 0), |(acc1, acc2), (head, _)| {
+            let (p1, p2) = trails(grid, head);
+            (acc1 + p1, acc2 + p2)
+        })
+}
+
+/// The number of summits reachable from `head`, and the number of distinct trails to them.
+fn trails(grid: &Grid<u8>, head: Point) -> (usize, usize) {
+    // Every step climbs by one, so every trail to a summit is a shortest path.
+    let search = search::bfs(head, |&p| {
+        grid.neighbors(p).filter(move |&n| grid[n] == grid[p] + 1)
+    });
+
+    search
+        .distances()
+        .keys()
+        .filter(|&&p| grid[p] == 9)
+        .fold((0, 0), |(summits, trails), p| {
+            (summits + 1, trails + search.count_paths(p))
+        })
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use crate::days::{parse, Answer, Solution};
+
+fn blink_opt(n: usize) -> (usize, Option<usize>) {
+    match n {
+        0 => (1, None),
+        n if n.checked_ilog10().unwrap_or(0) & 1 == 1 => {
+            let as_str = n.to_string();
+            let len = as_str.len();
+
+            let (first, last) = as_str.split_at(len / 2);
+            (first.parse().unwrap(), Some(last.parse().unwrap()))
+        }
+        n => (n * 2024, None),
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<usize, usize>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        let mut freq_count: HashMap<_, _> = HashMap::new();
+        for n in input.trim_end().split(' ') {
+            let n = parse::number(input, n)?;
+            freq_count.entry(n).and_modify(|v| *v += 1).or_insert(1);
+        }
+
+        Ok(freq_count)
+    }
+
+    fn part1(&self, freq_count: &Self::Input) -> Answer {
+        solve(&mut freq_count.clone(), 25).into()
+    }
+
+    fn part2(&self, freq_count: &Self::Input) -> Answer {
+        solve(&mut freq_count.clone(), 75).into()
+    }
+}
+
+fn solve(freq_count: &mut HashMap<usize, usize>, rounds: usize) -> usize {
+    for _ in 0..rounds {
+        let k: Vec<(usize, usize)> = freq_count.iter().map(|(k, v)| (*k, *v)).collect();
+        for (num, freq) in k {
+            let (first, last) = blink_opt(num);
+
+            freq_count.entry(num).and_modify(|v| *v -= freq);
+            freq_count
+                .entry(first)
+                .and_modify(|e| *e += freq)
+                .or_insert(freq);
+
+            if let Some(last) = last {
+                freq_count
+                    .entry(last)
+                    .and_modify(|e| *e += freq)
+                    .or_insert(freq);
+            }
+        }
+    }
+    freq_count.values().sum::<usize>()
+}
+
+#[cfg(test)]
+mod day11_test {
+    use std::collections::HashMap;
+
+    use super::{blink_opt, solve};
+    use crate::days::prop::{self, Rng};
+
+    /// Applies the rules to the engraving as written, splitting the digits as text.
+    fn naive_blink(stone: &str) -> Vec<String> {
+        if stone == "0" {
+            vec!["1".to_string()]
+        } else if stone.len().is_multiple_of(2) {
+            let (first, last) = stone.split_at(stone.len() / 2);
+            let last = last.trim_start_matches('0');
+            vec![
+                first.to_string(),
+                if last.is_empty() { "0" } else { last }.to_string(),
+            ]
+        } else {
+            vec![(stone.parse::<usize>().unwrap() * 2024).to_string()]
+        }
+    }
+
+    fn stone(rng: &mut Rng) -> usize {
+        if rng.chance(20) {
+            0
+        } else {
+            let digits = rng.range(1..=12) as u32;
+            rng.range(1..=10usize.pow(digits))
+        }
+    }
+
+    #[test]
+    fn test_blink() {
+        prop::check(stone, |&n| {
+            let (first, last) = blink_opt(n);
+            let blinked = std::iter::once(first)
+                .chain(last)
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(blinked, naive_blink(&n.to_string()));
+        });
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let stones_and_rounds = |rng: &mut Rng| {
+            let stones = rng.vec(1..=6, |rng| rng.range(0..=10_000));
+            (stones, rng.range(0..=15))
+        };
+        prop::check(stones_and_rounds, |(stones, rounds)| {
+            let mut line = stones.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+            for _ in 0..*rounds {
+                line = line.iter().flat_map(|stone| naive_blink(stone)).collect();
+            }
+
+            let mut counts = HashMap::new();
+            for &n in stones {
+                *counts.entry(n).or_default() += 1;
+            }
+            assert_eq!(solve(&mut counts, *rounds), line.len());
+        });
+    }
+}
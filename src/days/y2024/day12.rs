@@ -0,0 +1,88 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::days::{
+    grid::{Direction, Grid, Point},
+    parse, Answer, Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Grid::parse(input, Some)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        let (part1, _) = solve(grid);
+        part1.into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        let (_, part2) = solve(grid);
+        part2.into()
+    }
+}
+
+fn solve(grid: &Grid<char>) -> (usize, usize) {
+    let mut seen = HashSet::new();
+    grid.points()
+        .filter_map(|p| bfs(grid, p, &mut seen))
+        .fold((0, 0), |(p1, p2), (area, perim, sides)| {
+            (p1 + area * perim, p2 + area * sides)
+        })
+}
+
+fn count_corners(grid: &Grid<char>, p: Point) -> usize {
+    let kind = grid[p];
+    let same = |dx, dy| grid.get(p + Point::new(dx, dy)) == Some(&kind);
+
+    let up = same(0, -1);
+    let down = same(0, 1);
+    let up_left = same(-1, -1);
+    let up_right = same(1, -1);
+    let down_right = same(1, 1);
+    let down_left = same(-1, 1);
+    let right = same(1, 0);
+    let left = same(-1, 0);
+
+    !(up || right) as usize
+        + !(up || left) as usize
+        + !(down || right) as usize
+        + !(down || left) as usize
+        + (left && down && !down_left) as usize
+        + (right && down && !down_right) as usize
+        + (up && left && !up_left) as usize
+        + (up && right && !up_right) as usize
+}
+
+/// Floods the region containing `head`, returning its area, perimeter and number of
+/// corners, which is also its number of sides.
+fn bfs(grid: &Grid<char>, head: Point, seen: &mut HashSet<Point>) -> Option<(usize, usize, usize)> {
+    if !seen.insert(head) {
+        return None;
+    }
+
+    let kind = grid[head];
+    let mut queue = VecDeque::from([head]);
+    let mut area = 0;
+    let mut perimeter = 0;
+    let mut corners = 0;
+
+    while let Some(p) = queue.pop_front() {
+        area += 1;
+        corners += count_corners(grid, p);
+
+        for dir in Direction::ALL {
+            let next = p.step(dir);
+            if grid.get(next) != Some(&kind) {
+                perimeter += 1;
+            } else if seen.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Some((area, perimeter, corners))
+}
@@ -0,0 +1,168 @@
+use crate::days::{parse, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    a: (isize, isize),
+    b: (isize, isize),
+    prize: (isize, isize),
+}
+
+impl Machine {
+    fn parse_line(
+        input: &str,
+        line: Option<&str>,
+        prefix: &str,
+        sep: char,
+    ) -> parse::Result<(isize, isize)> {
+        let line = line.ok_or_else(|| ParseError::eof(input, format!("expected `{prefix}`")))?;
+        let values = parse::strip_prefix(input, line, prefix)?;
+        let (x, y) = parse::split_once(input, values, ", ")?;
+        let x = parse::strip_prefix(input, x, &format!("X{sep}"))?;
+        let y = parse::strip_prefix(input, y, &format!("Y{sep}"))?;
+
+        Ok((parse::number(input, x)?, parse::number(input, y)?))
+    }
+
+    fn for_part2(&mut self) -> &Self {
+        self.prize.0 += 10000000000000;
+        self.prize.1 += 10000000000000;
+        self
+    }
+
+    fn new(input: &str, block: &str) -> parse::Result<Self> {
+        let mut lines = block.lines();
+
+        let a = Self::parse_line(input, lines.next(), "Button A: ", '+')?;
+        let b = Self::parse_line(input, lines.next(), "Button B: ", '+')?;
+        let prize = Self::parse_line(input, lines.next(), "Prize: ", '=')?;
+
+        if let Some(extra) = lines.find(|l| !l.is_empty()) {
+            return Err(ParseError::at(input, extra, "expected a blank line"));
+        }
+
+        Ok(Self { a, b, prize })
+    }
+
+    fn solve_system(&self) -> (usize, usize) {
+        // c1 * self.a.0 + c2 * self.b.0 = self.prize.0;
+        // c1 * self.a.1 + c2 * self.b.1 = self.prize.1;
+
+        let determinant = (self.a.0 * self.b.1) - (self.b.0 * self.a.1);
+        let x_determinant = (self.prize.0 * self.b.1) - (self.prize.1 * self.b.0);
+        let y_determinant = (self.a.0 * self.prize.1) - (self.a.1 * self.prize.0);
+
+        let c1 = x_determinant / determinant;
+        let c2 = y_determinant / determinant;
+
+        // A negative number of presses is a solution of the system, but not of the claw.
+        if c1 >= 0
+            && c2 >= 0
+            && c1 * self.a.0 + c2 * self.b.0 == self.prize.0
+            && c1 * self.a.1 + c2 * self.b.1 == self.prize.1
+        {
+            (c1 as usize, c2 as usize)
+        } else {
+            (0, 0)
+        }
+    }
+
+    fn calculate_tokens(&self) -> usize {
+        let (c1, c2) = self.solve_system();
+        (3 * c1) + c2
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        input
+            .split("\n\n")
+            .filter(|block| !block.trim().is_empty())
+            .map(|block| Machine::new(input, block))
+            .collect()
+    }
+
+    fn part1(&self, machines: &Self::Input) -> Answer {
+        part1(machines).into()
+    }
+
+    fn part2(&self, machines: &Self::Input) -> Answer {
+        part2(&mut machines.clone()).into()
+    }
+}
+
+fn part1(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .fold(0, |acc, cur| acc + cur.calculate_tokens())
+}
+
+fn part2(machines: &mut [Machine]) -> usize {
+    machines
+        .iter_mut()
+        .fold(0, |acc, cur| acc + cur.for_part2().calculate_tokens())
+}
+
+#[cfg(test)]
+mod day13_test {
+    use super::Machine;
+    use crate::days::prop::{self, Rng};
+
+    /// A machine whose buttons don't move the claw in the same direction, as the
+    /// puzzle's never do. Half of the prizes are some presses of each button away,
+    /// possibly negative ones, the others anywhere.
+    fn machine(rng: &mut Rng) -> Machine {
+        let button = |rng: &mut Rng| (rng.range(1..=99) as isize, rng.range(1..=99) as isize);
+        let (a, b) = loop {
+            let (a, b) = (button(rng), button(rng));
+            if a.0 * b.1 != a.1 * b.0 {
+                break (a, b);
+            }
+        };
+
+        let prize = if rng.chance(50) {
+            let c1 = rng.range(0..=120) as isize - 10;
+            let c2 = rng.range(0..=120) as isize - 10;
+            (c1 * a.0 + c2 * b.0, c1 * a.1 + c2 * b.1)
+        } else {
+            (
+                rng.range(0..=20_000) as isize,
+                rng.range(0..=20_000) as isize,
+            )
+        };
+
+        Machine { a, b, prize }
+    }
+
+    #[test]
+    fn test_solve_system() {
+        prop::check(machine, |machine| {
+            let (c1, c2) = machine.solve_system();
+            if (c1, c2) != (0, 0) {
+                let (c1, c2) = (c1 as isize, c2 as isize);
+                assert_eq!(c1 * machine.a.0 + c2 * machine.b.0, machine.prize.0);
+                assert_eq!(c1 * machine.a.1 + c2 * machine.b.1, machine.prize.1);
+            }
+        });
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        prop::check(machine, |machine| {
+            // Trying every number of presses of A that doesn't overshoot the prize,
+            // with the number of presses of B that makes up the rest of the X axis.
+            let (a, b, prize) = (machine.a, machine.b, machine.prize);
+            let cheapest = (0..=prize.0 / a.0)
+                .filter(|c1| (prize.0 - c1 * a.0) % b.0 == 0)
+                .map(|c1| (c1, (prize.0 - c1 * a.0) / b.0))
+                .filter(|&(c1, c2)| c2 >= 0 && c1 * a.1 + c2 * b.1 == prize.1)
+                .map(|(c1, c2)| 3 * c1 as usize + c2 as usize)
+                .min()
+                .unwrap_or(0);
+            assert_eq!(machine.calculate_tokens(), cheapest);
+        });
+    }
+}
@@ -0,0 +1,432 @@
+use std::io::{self, BufRead};
+
+use itertools::Itertools;
+
+use crate::days::{
+    grid::{Grid, Point},
+    parse::{self, ParseError},
+    visual::{self, Canvas, Color},
+    Answer, Solution,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Robot {
+    pos: (isize, isize),
+    v: (isize, isize),
+}
+
+impl Robot {
+    fn parse_part(input: &str, p: &str, prefix: &str) -> parse::Result<(isize, isize)> {
+        let nums = parse::strip_prefix(input, p, prefix)?;
+        let (x, y) = parse::split_once(input, nums, ",")?;
+
+        Ok((parse::number(input, x)?, parse::number(input, y)?))
+    }
+
+    fn from_line(input: &str, line: &str) -> parse::Result<Self> {
+        let (pos, vel) = parse::split_once(input, line, " ")?;
+
+        Ok(Self {
+            pos: Self::parse_part(input, pos, "p=")?,
+            v: Self::parse_part(input, vel, "v=")?,
+        })
+    }
+
+    fn walk(&mut self, times: isize, grid_bounds: (isize, isize)) {
+        let (x, y) = self.pos;
+        let (dx, dy) = self.v;
+        let (grid_x, grid_y) = grid_bounds;
+
+        let new_x = (x + dx * times).rem_euclid(grid_x + 1);
+        let new_y = (y + dy * times).rem_euclid(grid_y + 1);
+
+        self.pos = (new_x, new_y);
+    }
+
+    fn get_quadrant(
+        &self,
+        counter: &mut (usize, usize, usize, usize),
+        grid_bounds: (isize, isize),
+    ) {
+        let (x, y) = self.pos;
+        let (grid_x, grid_y) = grid_bounds;
+        let divider_x = grid_x / 2;
+        let divider_y = grid_y / 2;
+
+        if x < divider_x && y < divider_y {
+            counter.0 += 1;
+        } else if x < divider_x && y > divider_y {
+            counter.1 += 1;
+        } else if x > divider_x && y < divider_y {
+            counter.2 += 1;
+        } else if x > divider_x && y > divider_y {
+            counter.3 += 1;
+        }
+    }
+}
+
+/// The size of the space the robots move in, unless the input's `Size` header says
+/// otherwise.
+const SIZE: (usize, usize) = (101, 103);
+
+/// The robots, and the largest coordinates of the space they move in.
+#[derive(Debug)]
+pub struct Lobby {
+    bots: Vec<Robot>,
+    grid_bounds: (isize, isize),
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Lobby;
+
+    /// Reads the robots after an optional `Size: <w>x<h>` header for the examples,
+    /// which use a smaller space.
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        let (size, rest) = parse::header(input, "Size");
+        let (width, height) = size.map_or(Ok(SIZE), |size| parse::size(input, size))?;
+        let bots = rest
+            .lines()
+            .map(|line| Robot::from_line(input, line))
+            .collect::<parse::Result<Vec<_>>>()?;
+        if bots.is_empty() {
+            return Err(ParseError::eof(input, "expected at least one robot"));
+        }
+
+        Ok(Lobby {
+            bots,
+            grid_bounds: (width as isize - 1, height as isize - 1),
+        })
+    }
+
+    fn part1(&self, lobby: &Self::Input) -> Answer {
+        part1(&lobby.bots, lobby.grid_bounds).into()
+    }
+
+    fn part2(&self, lobby: &Self::Input) -> Answer {
+        part2(&lobby.bots, lobby.grid_bounds).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn explore(&self, lobby: &Self::Input, commands: &mut dyn BufRead) -> Option<io::Result<()>> {
+        Some(explore(&lobby.bots, lobby.grid_bounds, commands).map(|_| ()))
+    }
+}
+
+/// A command typed while exploring the robots' positions over time.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Forward(isize),
+    Back(isize),
+    Jump(isize),
+    /// The next step where the spread along the x axis, y axis or both is low.
+    LowVariance {
+        x: bool,
+        y: bool,
+    },
+    Help,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("n");
+        let count = match words.next() {
+            Some(n) => n.parse().map_err(|_| format!("invalid number '{n}'"))?,
+            None if command == "g" => return Err("g requires a step".to_string()),
+            None => 1,
+        };
+        if let Some(extra) = words.next() {
+            return Err(format!("unexpected '{extra}'"));
+        }
+
+        match command {
+            "n" => Ok(Command::Forward(count)),
+            "b" => Ok(Command::Back(count)),
+            "g" => Ok(Command::Jump(count)),
+            "v" => Ok(Command::LowVariance { x: true, y: true }),
+            "x" => Ok(Command::LowVariance { x: true, y: false }),
+            "y" => Ok(Command::LowVariance { x: false, y: true }),
+            "h" | "?" => Ok(Command::Help),
+            "q" => Ok(Command::Quit),
+            _ => Err(format!("unknown command '{command}'")),
+        }
+    }
+}
+
+const EXPLORE_HELP: &str = "Commands:
+    n [N]  Forward N steps (default 1, also an empty line)
+    b [N]  Back N steps
+    g N    Jump to step N
+    v      Next step with low variance along both axes
+    x, y   Next step with low variance along that axis
+    h      Show this message
+    q      Quit";
+
+fn walked(bots: &[Robot], step: isize, grid_bounds: (isize, isize)) -> Vec<Robot> {
+    bots.iter()
+        .map(|&b| {
+            let mut b = b;
+            b.walk(step, grid_bounds);
+            b
+        })
+        .collect_vec()
+}
+
+/// The first step after `from` whose variances are below half their averages over a
+/// full cycle, along the selected axes.
+fn next_low_variance(
+    bots: &[Robot],
+    grid_bounds: (isize, isize),
+    from: isize,
+    x: bool,
+    y: bool,
+) -> Option<isize> {
+    let (w, h) = (grid_bounds.0 + 1, grid_bounds.1 + 1);
+    let average = |period: isize, variance: fn(&[Robot]) -> usize| {
+        (0..period)
+            .map(|i| variance(&walked(bots, i, grid_bounds)))
+            .sum::<usize>()
+            / period as usize
+    };
+    let (avg_x, avg_y) = (average(w, x_variance), average(h, y_variance));
+
+    (from + 1..=from + w * h).find(|&i| {
+        let bs = walked(bots, i, grid_bounds);
+        (!x || x_variance(&bs) < avg_x / 2) && (!y || y_variance(&bs) < avg_y / 2)
+    })
+}
+
+/// Steps through the robots' positions over time, driven by `commands`. Returns the
+/// step it was left at.
+fn explore(
+    bots: &[Robot],
+    grid_bounds: (isize, isize),
+    commands: &mut dyn BufRead,
+) -> io::Result<isize> {
+    let mut step = 0;
+    let mut lines = commands.lines();
+
+    loop {
+        draw(
+            &walked(bots, step, grid_bounds),
+            grid_bounds,
+            &format!("Walked {step}"),
+        );
+        eprint!("step {step}> ");
+
+        let Some(line) = lines.next().transpose()? else {
+            eprintln!();
+            return Ok(step);
+        };
+        match Command::parse(&line) {
+            Ok(Command::Forward(n)) => step = (step + n).max(0),
+            Ok(Command::Back(n)) => step = (step - n).max(0),
+            Ok(Command::Jump(n)) => step = n.max(0),
+            Ok(Command::LowVariance { x, y }) => {
+                match next_low_variance(bots, grid_bounds, step, x, y) {
+                    Some(found) => step = found,
+                    None => eprintln!("no low variance step found"),
+                }
+            }
+            Ok(Command::Help) => eprintln!("{EXPLORE_HELP}"),
+            Ok(Command::Quit) => return Ok(step),
+            Err(e) => eprintln!("{e}\n{EXPLORE_HELP}"),
+        }
+    }
+}
+
+fn part1(bots: &[Robot], grid_bounds: (isize, isize)) -> usize {
+    let quadrants = bots
+        .iter()
+        .map(|&bot| {
+            let mut r = bot;
+            r.walk(100, grid_bounds);
+            r
+        })
+        .fold((0, 0, 0, 0), |mut acc, cur| {
+            cur.get_quadrant(&mut acc, grid_bounds);
+            acc
+        });
+
+    quadrants.0 * quadrants.1 * quadrants.2 * quadrants.3
+}
+
+/// The first step where the robots bunch up along both axes, from the steps where each
+/// axis alone is least spread out. `None` when the two periods, the width and height,
+/// share a factor, so that the steps can't be combined.
+fn part2(bots: &[Robot], grid_bounds: (isize, isize)) -> Option<usize> {
+    let (h, w) = (grid_bounds.1 + 1, grid_bounds.0 + 1);
+    // w * inverse = 1 (mod h), so that bx + k * w lands on by (mod h).
+    let inverse = (1..h).find(|k| (k * w).rem_euclid(h) == 1)?;
+
+    let mut x_variances = vec![];
+    let mut y_variances = vec![];
+    for i in 0..w.max(h) {
+        let bs = bots
+            .iter()
+            .map(|&b| {
+                let mut b = b;
+                b.walk(i + 1, grid_bounds);
+                b
+            })
+            .collect_vec();
+
+        let x_var = x_variance(&bs);
+        let y_var = y_variance(&bs);
+        x_variances.push((i + 1, x_var));
+        y_variances.push((i + 1, y_var));
+    }
+
+    let mut bx = 0;
+    let mut min_var_x = usize::MAX;
+    for (sec, var) in &x_variances {
+        if *var < min_var_x {
+            bx = *sec;
+            min_var_x = *var;
+        }
+    }
+
+    let mut by = 0;
+    let mut min_var_y = usize::MAX;
+    for (sec, var) in &y_variances {
+        if *var < min_var_y {
+            by = *sec;
+            min_var_y = *var;
+        }
+    }
+
+    let t = bx + (((inverse * (by - bx)).rem_euclid(h)) * w);
+
+    if visual::enabled() {
+        draw(
+            &walked(bots, t, grid_bounds),
+            grid_bounds,
+            &format!("Walked {t}"),
+        );
+    }
+
+    Some(t as usize)
+}
+
+fn x_variance(bots: &[Robot]) -> usize {
+    let n = bots.len();
+    let xs = bots.iter().map(|b| b.pos.0 as usize).collect_vec();
+    let avg = xs.iter().sum::<usize>() / n;
+
+    let mut var = 0;
+    for x in xs {
+        var += avg.abs_diff(x).pow(2);
+    }
+
+    var /= n;
+
+    var
+}
+
+fn y_variance(bots: &[Robot]) -> usize {
+    let n = bots.len();
+    let ys = bots.iter().map(|b| b.pos.1 as usize).collect_vec();
+    let avg = ys.iter().sum::<usize>() / n;
+
+    let mut var = 0;
+    for y in ys {
+        var += avg.abs_diff(y).pow(2);
+    }
+
+    var /= n;
+    var
+}
+
+const PALETTE: &[(char, Color)] = &[('*', Color::Green)];
+
+fn draw(bots: &[Robot], grid_bounds: (isize, isize), caption: &str) {
+    let (x, y) = grid_bounds;
+    let mut grid = Grid::new(x as usize + 1, y as usize + 1, ' ');
+    for b in bots {
+        grid[Point::new(b.pos.0, b.pos.1)] = '*';
+    }
+    let mut canvas = Canvas::new(&grid);
+    canvas.palette(PALETTE);
+
+    visual::frame(&canvas, caption);
+}
+
+#[cfg(test)]
+mod day14_test {
+    use std::io::Cursor;
+
+    use super::{explore, next_low_variance, Command, Day14, Robot};
+    use crate::days::Solution;
+
+    #[test]
+    fn test_commands() {
+        assert_eq!(Command::parse(""), Ok(Command::Forward(1)));
+        assert_eq!(Command::parse("b 10"), Ok(Command::Back(10)));
+        assert_eq!(Command::parse("g 7051"), Ok(Command::Jump(7051)));
+        assert_eq!(
+            Command::parse("x"),
+            Ok(Command::LowVariance { x: true, y: false })
+        );
+        assert!(Command::parse("g").is_err());
+        assert!(Command::parse("n many").is_err());
+        assert!(Command::parse("z").is_err());
+    }
+
+    #[test]
+    fn test_no_robots() {
+        for (input, error) in [
+            ("", "line 1, column 1: expected at least one robot"),
+            (
+                "Size: 11x7\n",
+                "line 2, column 1: expected at least one robot",
+            ),
+        ] {
+            assert_eq!(
+                Day14.parse(input).unwrap_err().to_string(),
+                error,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_explore() {
+        let bots = [Robot {
+            pos: (2, 4),
+            v: (2, -3),
+        }];
+        let step = |commands: &str| explore(&bots, (10, 6), &mut Cursor::new(commands)).unwrap();
+        assert_eq!(step("n 5\n"), 5);
+        assert_eq!(step("n 5\nb 2\n"), 3);
+        assert_eq!(step("n 5\nb 2\nwat\n\n"), 4);
+        assert_eq!(step("g 3\nb 10\n"), 0);
+        assert_eq!(step("g 7\nq\nn\n"), 7);
+    }
+
+    /// Eleven robots on an 11 by 11 grid, with every column and row speed once, which
+    /// all meet in the middle at step 3 and are spread over every column and row at
+    /// any other step of a cycle.
+    fn meeting() -> Vec<Robot> {
+        (0..11_isize)
+            .map(|vx| {
+                let v = (vx, 2 * vx % 11);
+                let pos = ((5 - 3 * v.0).rem_euclid(11), (5 - 3 * v.1).rem_euclid(11));
+                Robot { pos, v }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_low_variance() {
+        let bots = meeting();
+        assert_eq!(next_low_variance(&bots, (10, 10), 0, true, true), Some(3));
+        assert_eq!(next_low_variance(&bots, (10, 10), 0, true, false), Some(3));
+        assert_eq!(next_low_variance(&bots, (10, 10), 3, false, true), Some(14));
+
+        let step = |commands: &str| explore(&bots, (10, 10), &mut Cursor::new(commands)).unwrap();
+        assert_eq!(step("v\n"), 3);
+        assert_eq!(step("v\nv\nb 1\n"), 13);
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::days::{
+    grid::{Direction, Grid, Point},
+    parse,
+    visual::{self, Canvas, Color},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Robot,
+    Box,
+    Wall,
+    Empty,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Kind {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '@' => Some(Kind::Robot),
+            'O' => Some(Kind::Box),
+            '#' => Some(Kind::Wall),
+            '.' => Some(Kind::Empty),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Object {
+    kind: Kind,
+}
+
+impl Object {
+    fn movable(&self) -> bool {
+        !matches!(self.kind, Kind::Wall)
+    }
+    fn new(kind: Kind) -> Self {
+        Self { kind }
+    }
+
+    fn new_part2(kind: Kind) -> Vec<Self> {
+        match kind {
+            Kind::Robot => vec![Self { kind: Kind::Robot }, Self { kind: Kind::Empty }],
+            Kind::Box | Kind::BoxLeft | Kind::BoxRight => vec![
+                Self {
+                    kind: Kind::BoxLeft,
+                },
+                Self {
+                    kind: Kind::BoxRight,
+                },
+            ],
+            Kind::Wall => vec![Self { kind: Kind::Wall }, Self { kind: Kind::Wall }],
+            Kind::Empty => vec![Self { kind: Kind::Empty }, Self { kind: Kind::Empty }],
+        }
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self.kind {
+            Kind::Box => 'O',
+            Kind::Wall => '#',
+            Kind::Robot => '@',
+            Kind::Empty => '.',
+            Kind::BoxLeft => '[',
+            Kind::BoxRight => ']',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+const PALETTE: &[(char, Color)] = &[
+    ('#', Color::Gray),
+    ('O', Color::Rgb(170, 120, 60)),
+    ('[', Color::Rgb(170, 120, 60)),
+    (']', Color::Rgb(170, 120, 60)),
+    ('@', Color::Yellow),
+];
+
+#[derive(Debug)]
+struct Warehouse {
+    bot_position: Point,
+    values: Grid<Object>,
+}
+
+impl Warehouse {
+    /// The walls around the map, checked by [`parse`], keep every move on it.
+    fn at(&self, p: Point) -> Object {
+        self.values[p]
+    }
+
+    fn can_move(&self, pos: Point, m: Direction, checking_side: bool) -> bool {
+        let obj = self.at(pos);
+
+        match obj.kind {
+            Kind::Empty => true,
+            Kind::Wall => false,
+            Kind::BoxLeft if !checking_side => {
+                let rhs = pos.step(Direction::Right);
+                assert!(self.at(rhs).kind == Kind::BoxRight);
+                if m == Direction::Right {
+                    self.can_move(rhs, m, true)
+                } else {
+                    self.can_move(pos.step(m), m, false) && self.can_move(rhs, m, true)
+                }
+            }
+            Kind::BoxRight if !checking_side => {
+                let lhs = pos.step(Direction::Left);
+                assert!(self.at(lhs).kind == Kind::BoxLeft);
+                if m == Direction::Left {
+                    self.can_move(lhs, m, true)
+                } else {
+                    self.can_move(pos.step(m), m, false) && self.can_move(lhs, m, true)
+                }
+            }
+            _ => obj.movable() && self.can_move(pos.step(m), m, false),
+        }
+    }
+
+    fn do_move(&mut self, pos: Point, m: Direction, moving_side: bool) {
+        let obj = self.at(pos);
+
+        if obj.kind == Kind::Empty {
+            return;
+        }
+
+        if obj.kind == Kind::BoxLeft && !moving_side {
+            let rhs = pos.step(Direction::Right);
+            assert!(self.at(rhs).kind == Kind::BoxRight);
+            self.do_move(rhs, m, true);
+        }
+
+        if obj.kind == Kind::BoxRight && !moving_side {
+            let lhs = pos.step(Direction::Left);
+            assert!(self.at(lhs).kind == Kind::BoxLeft);
+            self.do_move(lhs, m, true);
+        }
+
+        if !obj.movable() {
+            return;
+        }
+
+        let new_pos = pos.step(m);
+        self.do_move(new_pos, m, false);
+
+        self.values[new_pos] = self.values[pos];
+        self.values[pos] = Object { kind: Kind::Empty };
+
+        if obj.kind == Kind::Robot {
+            self.bot_position = new_pos;
+        }
+    }
+
+    fn draw(&self, caption: &str) {
+        let mut canvas = Canvas::from_display(&self.values);
+        canvas.palette(PALETTE);
+
+        visual::frame(&canvas, caption);
+    }
+
+    fn get_gps_sum(&self) -> isize {
+        self.values
+            .iter()
+            .map(|(p, obj)| match obj.kind {
+                Kind::Box | Kind::BoxLeft => 100 * p.y + p.x,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+
+/// The warehouse's map, where its robot starts and the moves it will try.
+#[derive(Debug)]
+pub struct Plan {
+    map: Grid<Kind>,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Plan;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn parse(input: &str) -> parse::Result<Plan> {
+    let [map, moves] = parse::sections(input)?;
+
+    let map = Grid::parse(map, Kind::from_char)?;
+    let robot = map.find_one(input, "robot", |&k| k == Kind::Robot)?;
+
+    // The first gap in the walls around the map, in reading order.
+    fn gap<'a>(cells: impl IntoIterator<Item = &'a Kind>) -> Option<usize> {
+        cells.into_iter().position(|&k| k != Kind::Wall)
+    }
+    let (right, bottom) = (map.width() - 1, map.height() - 1);
+    let mut columns = map.columns();
+    let (left_side, right_side) = (columns.next(), columns.last());
+    let gaps = [
+        gap(map.row(0)).map(|x| (0, x)),
+        gap(map.row(bottom)).map(|x| (bottom, x)),
+        left_side.and_then(gap).map(|y| (y, 0)),
+        right_side.and_then(gap).map(|y| (y, right)),
+    ];
+    if let Some((y, x)) = gaps.into_iter().flatten().min() {
+        let line = input.lines().nth(y).unwrap_or_default();
+        return Err(ParseError::at(
+            input,
+            &line[x..],
+            "expected the map to be surrounded by walls",
+        ));
+    }
+
+    let moves = moves
+        .char_indices()
+        .filter(|&(_, c)| c != '\n')
+        .map(|(i, c)| {
+            Direction::from_arrow(c).ok_or_else(|| {
+                ParseError::at(input, &moves[i..], format!("expected a move, found `{c}`"))
+            })
+        })
+        .collect::<parse::Result<_>>()?;
+
+    Ok(Plan { map, robot, moves })
+}
+
+fn warehouse(plan: &Plan, part: u8) -> Warehouse {
+    let map = &plan.map;
+    let (values, bot_position) = if part == 1 {
+        (map.map(|&kind| Object::new(kind)), plan.robot)
+    } else {
+        let mut wide = Grid::new(map.width() * 2, map.height(), Object::new(Kind::Empty));
+        for (p, &kind) in map.iter() {
+            for (dx, object) in Object::new_part2(kind).into_iter().enumerate() {
+                wide[Point::new(p.x * 2 + dx as isize, p.y)] = object;
+            }
+        }
+        (wide, Point::new(plan.robot.x * 2, plan.robot.y))
+    };
+
+    Warehouse {
+        bot_position,
+        values,
+    }
+}
+
+fn part1(plan: &Plan) -> isize {
+    solve(&mut warehouse(plan, 1), &plan.moves)
+}
+
+fn part2(plan: &Plan) -> isize {
+    solve(&mut warehouse(plan, 2), &plan.moves)
+}
+
+fn solve(warehouse: &mut Warehouse, moves: &[Direction]) -> isize {
+    for (i, &m) in moves.iter().enumerate() {
+        if warehouse.can_move(warehouse.bot_position, m, false) {
+            warehouse.do_move(warehouse.bot_position, m, false);
+        }
+        if visual::enabled() {
+            warehouse.draw(&format!("Move {}/{}: {}", i + 1, moves.len(), m.arrow()));
+        }
+    }
+
+    warehouse.get_gps_sum()
+}
+
+#[cfg(test)]
+mod day15_test {
+    use super::parse;
+
+    #[test]
+    fn test_parse_errors() {
+        for (input, error) in [
+            (
+                "####\n#@.#\n#...\n####\n\n<\n",
+                "line 3, column 4: expected the map to be surrounded by walls",
+            ),
+            (
+                "####\n#..#\n####\n\n<\n",
+                "line 6, column 1: expected a robot",
+            ),
+            (
+                "####\n#@.#\n####\n\n<x\n",
+                "line 5, column 2: expected a move, found `x`",
+            ),
+        ] {
+            assert_eq!(parse(input).unwrap_err().to_string(), error, "{input}");
+        }
+
+        let plan = parse("####\n#.@#\n####\n\n<\n").unwrap();
+        assert_eq!((plan.robot.x, plan.robot.y), (2, 1));
+    }
+}
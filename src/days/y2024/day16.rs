@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::days::{
+    grid::{Direction, Grid, Point},
+    parse, search,
+    visual::{self, Canvas, Color},
+    Answer, Solution,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Start,
+    End,
+    Free,
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Free),
+            '#' => Some(Self::Wall),
+            'S' => Some(Self::Start),
+            'E' => Some(Self::End),
+            _ => None,
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Tile::End => 'E',
+            Tile::Wall => '#',
+            Tile::Free => '.',
+            Tile::Start => 'S',
+        }
+    }
+}
+
+const PALETTE: &[(char, Color)] = &[
+    ('#', Color::Gray),
+    ('S', Color::Yellow),
+    ('E', Color::Yellow),
+];
+
+/// The cost of turning from `from` to `to` before stepping forward.
+fn cost_change(from: Direction, to: Direction) -> usize {
+    if from == to {
+        0
+    } else if from.opposite() == to {
+        2000
+    } else {
+        1000
+    }
+}
+
+/// A reindeer's position and the way it faces.
+type State = (Point, Direction);
+
+pub struct Maze {
+    tiles: Grid<Tile>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    fn parse(input: &str) -> parse::Result<Maze> {
+        let tiles = Grid::parse(input, Tile::new)?;
+        let start = tiles.find_one(input, "start", |t| *t == Tile::Start)?;
+        let end = tiles.find_one(input, "end", |t| *t == Tile::End)?;
+
+        Ok(Maze { tiles, start, end })
+    }
+
+    fn canvas(&self) -> Canvas {
+        let mut canvas = Canvas::new(&self.tiles.map(Tile::symbol));
+        canvas.palette(PALETTE);
+        canvas
+    }
+
+    fn draw_path(&self, path: &[State], caption: &str) {
+        let mut canvas = self.canvas();
+        for &(pos, dir) in path {
+            canvas.set(pos, dir.arrow(), Color::Green);
+        }
+
+        visual::show(&canvas, caption);
+    }
+
+    /// Draws the tiles on a best path over every tile the search reached.
+    fn draw_best_nodes(&self, nodes: &HashSet<Point>, visited: impl Iterator<Item = Point>) {
+        let mut canvas = self.canvas();
+        canvas
+            .highlight(visited, Color::Blue)
+            .overlay(nodes.iter().copied(), 'O', Color::Green);
+
+        visual::show(&canvas, &format!("{} tiles on a best path", nodes.len()));
+    }
+
+    /// The states one step forward from `(pos, dir)`, turning first if needed.
+    fn moves(&self, &(pos, dir): &State) -> impl Iterator<Item = (State, usize)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| {
+            let next = pos.step(d);
+            (self.tiles.get(next)? != &Tile::Wall).then(|| ((next, d), 1 + cost_change(dir, d)))
+        })
+    }
+
+    fn lowest_cost(&self) -> Option<usize> {
+        let distance = |p: Point| p.x.abs_diff(self.end.x) + p.y.abs_diff(self.end.y);
+        search::astar(
+            (self.start, Direction::Right),
+            |s| self.moves(s),
+            |&(p, _)| distance(p),
+            |&(p, _)| p == self.end,
+        )
+        .map(|(cost, _)| cost)
+    }
+}
+
+fn solve(maze: &Maze) -> usize {
+    let search = search::dijkstra((maze.start, Direction::Right), |s| maze.moves(s));
+    let ends = Direction::ALL.map(|d| (maze.end, d));
+    let Some(lowest) = ends.iter().filter_map(|e| search.distance(e)).min() else {
+        return 0;
+    };
+    let best_ends = ends
+        .into_iter()
+        .filter(|e| search.distance(e) == Some(lowest));
+
+    if visual::enabled() {
+        let paths = best_ends
+            .clone()
+            .flat_map(|end| search.paths(&end))
+            .collect_vec();
+        for (i, path) in paths.iter().enumerate() {
+            maze.draw_path(
+                path,
+                &format!("Best path {}/{}, cost {lowest}", i + 1, paths.len()),
+            );
+        }
+    }
+
+    let unique_nodes = search
+        .on_paths(best_ends)
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect::<HashSet<_>>();
+    if visual::enabled() {
+        maze.draw_best_nodes(&unique_nodes, search.distances().keys().map(|&(p, _)| p));
+    }
+
+    unique_nodes.len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Maze::parse(input)
+    }
+
+    fn part1(&self, maze: &Self::Input) -> Answer {
+        maze.lowest_cost().map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(&self, maze: &Self::Input) -> Answer {
+        solve(maze).into()
+    }
+}
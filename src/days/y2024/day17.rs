@@ -0,0 +1,1688 @@
+use std::{
+    array,
+    collections::{BTreeSet, HashMap},
+    fmt,
+    hash::Hash,
+    io::{self, BufRead},
+};
+
+use itertools::Itertools;
+
+use crate::days::{parse, visual, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Adv(usize),
+    Bxl(usize),
+    Bst(usize),
+    Jnz(usize),
+    /// The operand is ignored, but kept so the program can be written back as it was.
+    Bxc(usize),
+    Out(usize),
+    Bdv(usize),
+    Cdv(usize),
+}
+
+/// The instructions' names, by opcode.
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+impl Op {
+    fn parse(op: usize, operand: usize) -> Option<Self> {
+        match op {
+            0 => Some(Op::Adv(operand)),
+            1 => Some(Op::Bxl(operand)),
+            2 => Some(Op::Bst(operand)),
+            3 => Some(Op::Jnz(operand)),
+            4 => Some(Op::Bxc(operand)),
+            5 => Some(Op::Out(operand)),
+            6 => Some(Op::Bdv(operand)),
+            7 => Some(Op::Cdv(operand)),
+            _ => None,
+        }
+    }
+}
+
+/// A combo operand as the register or literal it stands for.
+fn combo(operand: usize) -> String {
+    match operand {
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        7 => "?".to_string(),
+        n => n.to_string(),
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Op::Adv(operand) => write!(f, "adv {}", combo(operand)),
+            Op::Bxl(operand) => write!(f, "bxl {operand}"),
+            Op::Bst(operand) => write!(f, "bst {}", combo(operand)),
+            Op::Jnz(operand) => write!(f, "jnz {operand}"),
+            Op::Bxc(0) => write!(f, "bxc"),
+            Op::Bxc(operand) => write!(f, "bxc {operand}"),
+            Op::Out(operand) => write!(f, "out {}", combo(operand)),
+            Op::Bdv(operand) => write!(f, "bdv {}", combo(operand)),
+            Op::Cdv(operand) => write!(f, "cdv {}", combo(operand)),
+        }
+    }
+}
+
+/// Each instruction of the program with its address. A trailing opcode without an
+/// operand is shown as a bare number.
+fn instructions(ops: &[usize]) -> impl Iterator<Item = (usize, String)> + '_ {
+    ops.chunks(2).enumerate().map(|(i, pair)| match *pair {
+        [op, operand] => (i * 2, Op::parse(op, operand).unwrap().to_string()),
+        _ => (i * 2, pair[0].to_string()),
+    })
+}
+
+/// The program as one instruction per line, each after its address.
+fn disassemble(ops: &[usize]) -> String {
+    instructions(ops)
+        .map(|(address, instruction)| format!("{address:>3}: {instruction}"))
+        .join("\n")
+}
+
+/// An instruction read from assembly source, waiting for the labels to be known.
+struct Line<'a> {
+    mnemonic: &'a str,
+    operand: Option<&'a str>,
+}
+
+/// Assembles `source`, a slice of `input`, into the program's numbers. Each line holds
+/// an instruction like `adv 3`, `out a` or `jnz loop`, with combo operands written as
+/// the literals 0 to 3 or the registers A, B and C, and `jnz` taking an address or a
+/// label. A line may start with `label:` to name the address of its instruction, or
+/// with the address itself as the disassembler writes it, which must match. Anything
+/// after a `;` is a comment, and a bare number is emitted as it is.
+fn assemble(input: &str, source: &str) -> parse::Result<Vec<usize>> {
+    let mut labels = HashMap::new();
+    let mut lines = vec![];
+    let mut address = 0;
+
+    for line in source.lines() {
+        let mut code = line.split(';').next().unwrap().trim();
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            if let Ok(n) = label.parse::<usize>() {
+                if n != address {
+                    return Err(ParseError::at(
+                        input,
+                        label,
+                        format!("expected address {address}, found {n}"),
+                    ));
+                }
+            } else if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return Err(ParseError::at(input, label, "expected a label"));
+            } else if labels.insert(label, address).is_some() {
+                return Err(ParseError::at(
+                    input,
+                    label,
+                    format!("label `{label}` is defined twice"),
+                ));
+            }
+            code = rest.trim();
+        }
+
+        let mut words = code.split_whitespace();
+        let Some(mnemonic) = words.next() else {
+            continue;
+        };
+        let operand = words.next();
+        if let Some(extra) = words.next() {
+            return Err(ParseError::at(
+                input,
+                extra,
+                format!("unexpected `{extra}`"),
+            ));
+        }
+
+        address += if operand.is_none() && mnemonic.parse::<usize>().is_ok() {
+            1
+        } else {
+            2
+        };
+        lines.push(Line { mnemonic, operand });
+    }
+
+    let mut ops = vec![];
+    for Line { mnemonic, operand } in lines {
+        let three_bit = |n: &str| {
+            parse::number::<usize>(input, n).and_then(|value| match value {
+                0..=7 => Ok(value),
+                _ => Err(ParseError::at(
+                    input,
+                    n,
+                    format!("expected a 3-bit number, found `{value}`"),
+                )),
+            })
+        };
+
+        if mnemonic.parse::<usize>().is_ok() && operand.is_none() {
+            ops.push(three_bit(mnemonic)?);
+            continue;
+        }
+        let op = MNEMONICS
+            .iter()
+            .position(|&m| m.eq_ignore_ascii_case(mnemonic))
+            .ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    mnemonic,
+                    format!("expected an instruction, found `{mnemonic}`"),
+                )
+            })?;
+        let required = || {
+            operand.ok_or_else(|| {
+                ParseError::at(input, mnemonic, format!("`{mnemonic}` needs an operand"))
+            })
+        };
+
+        let operand = match op {
+            // bxl takes a literal, and bxc ignores its operand.
+            1 => three_bit(required()?)?,
+            4 => operand.map_or(Ok(0), three_bit)?,
+            3 => {
+                let target = required()?;
+                match labels.get(target) {
+                    Some(&address) if address > 7 => {
+                        return Err(ParseError::at(
+                            input,
+                            target,
+                            format!("label `{target}` is at {address}, out of reach of `jnz`"),
+                        ))
+                    }
+                    Some(&address) => address,
+                    None if target.parse::<usize>().is_err() => {
+                        return Err(ParseError::at(
+                            input,
+                            target,
+                            format!("unknown label `{target}`"),
+                        ))
+                    }
+                    None => three_bit(target)?,
+                }
+            }
+            _ => {
+                let operand = required()?;
+                match operand.to_ascii_uppercase().as_str() {
+                    "0" | "1" | "2" | "3" => three_bit(operand)?,
+                    "A" => 4,
+                    "B" => 5,
+                    "C" => 6,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            operand,
+                            format!("expected 0 to 3, A, B or C, found `{operand}`"),
+                        ))
+                    }
+                }
+            }
+        };
+        ops.extend([op, operand]);
+    }
+
+    Ok(ops)
+}
+
+/// What the machine's registers hold: an unsigned integer of a fixed width, or a
+/// [`Big`] one that never overflows.
+pub trait Register: Clone + Eq + Hash + fmt::Debug + fmt::Display {
+    /// The width in bits, `None` when there is no limit.
+    const BITS: Option<u32>;
+
+    fn from_usize(n: usize) -> Self;
+    /// Parses a decimal number, `None` if it isn't one or doesn't fit.
+    fn parse(s: &str) -> Option<Self>;
+    fn is_zero(&self) -> bool;
+    /// The lowest bits that fit in a `usize`.
+    fn truncate(&self) -> usize;
+    fn to_usize(&self) -> Option<usize>;
+    fn xor(&self, other: &Self) -> Self;
+    /// Shifted right by `by` bits, so 0 once `by` is the width or more.
+    fn shr(&self, by: usize) -> Self;
+}
+
+macro_rules! fixed_register {
+    ($($t:ty),*) => {$(
+        impl Register for $t {
+            const BITS: Option<u32> = Some(<$t>::BITS);
+
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+
+            fn parse(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            fn truncate(&self) -> usize {
+                *self as usize
+            }
+
+            fn to_usize(&self) -> Option<usize> {
+                usize::try_from(*self).ok()
+            }
+
+            fn xor(&self, other: &Self) -> Self {
+                self ^ other
+            }
+
+            fn shr(&self, by: usize) -> Self {
+                u32::try_from(by)
+                    .ok()
+                    .and_then(|by| self.checked_shr(by))
+                    .unwrap_or(0)
+            }
+        }
+    )*};
+}
+
+fixed_register!(u64, u128);
+
+/// An unsigned integer of any size, as 64-bit limbs from the least significant one,
+/// without zero limbs at the end.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Big(Vec<u64>);
+
+impl Big {
+    fn trimmed(mut limbs: Vec<u64>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self(limbs)
+    }
+
+    fn mul_add(&self, factor: u64, add: u64) -> Self {
+        let mut limbs = Vec::with_capacity(self.0.len() + 1);
+        let mut carry = add as u128;
+        for &limb in &self.0 {
+            let n = limb as u128 * factor as u128 + carry;
+            limbs.push(n as u64);
+            carry = n >> 64;
+        }
+        limbs.push(carry as u64);
+        Self::trimmed(limbs)
+    }
+
+    /// The quotient and remainder of dividing by `divisor`.
+    fn div_rem(&self, divisor: u64) -> (Self, u64) {
+        let mut limbs = vec![0; self.0.len()];
+        let mut rem = 0u128;
+        for (i, &limb) in self.0.iter().enumerate().rev() {
+            let n = rem << 64 | limb as u128;
+            limbs[i] = (n / divisor as u128) as u64;
+            rem = n % divisor as u128;
+        }
+        (Self::trimmed(limbs), rem as u64)
+    }
+}
+
+impl fmt::Display for Big {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 10_u64.pow(19);
+
+        let mut chunks = vec![];
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (quotient, rem) = n.div_rem(CHUNK);
+            chunks.push(rem);
+            n = quotient;
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{first}")?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{chunk:019}"))
+            }
+        }
+    }
+}
+
+impl Register for Big {
+    const BITS: Option<u32> = None;
+
+    fn from_usize(n: usize) -> Self {
+        Self::trimmed(vec![n as u64])
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(
+            s.bytes()
+                .fold(Self::default(), |n, b| n.mul_add(10, (b - b'0') as u64)),
+        )
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn truncate(&self) -> usize {
+        self.0.first().map_or(0, |&limb| limb as usize)
+    }
+
+    fn to_usize(&self) -> Option<usize> {
+        match self.0[..] {
+            [] => Some(0),
+            [limb] => usize::try_from(limb).ok(),
+            _ => None,
+        }
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        let limbs = (0..self.0.len().max(other.0.len()))
+            .map(|i| self.0.get(i).unwrap_or(&0) ^ other.0.get(i).unwrap_or(&0))
+            .collect();
+        Self::trimmed(limbs)
+    }
+
+    fn shr(&self, by: usize) -> Self {
+        let (limbs, bits) = (by / 64, by % 64);
+        let shifted = (limbs..self.0.len())
+            .map(|i| {
+                let high = match bits {
+                    0 => 0,
+                    _ => self.0.get(i + 1).map_or(0, |&next| next << (64 - bits)),
+                };
+                self.0[i] >> bits | high
+            })
+            .collect();
+        Self::trimmed(shifted)
+    }
+}
+
+/// What a division does when its divisor, a power of two, doesn't fit in the
+/// registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Stop with a [`Fault`].
+    Checked,
+    /// Divide by the power of two the width wraps the exponent to, as shifting a
+    /// register by the exponent would.
+    Wrapping,
+}
+
+/// Why the machine stopped in the middle of its program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fault {
+    /// The reserved combo operand 7, at this address.
+    ReservedOperand(usize),
+    /// A division, at this address, by a power of two too large for the registers.
+    Overflow(usize),
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::ReservedOperand(ip) => write!(f, "reserved combo operand 7 at {ip}"),
+            Fault::Overflow(ip) => write!(f, "division at {ip} overflows the registers"),
+        }
+    }
+}
+
+/// How many instructions a run may take before it is given up on.
+const STEP_BUDGET: usize = 1_000_000;
+
+/// How a run with a step budget ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Halted,
+    OutOfSteps(usize),
+    /// The registers and `ip` after `step` were the same as after `since`, so the
+    /// program would loop forever.
+    Cycle {
+        since: usize,
+        step: usize,
+    },
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Halted => write!(f, "halted"),
+            Outcome::OutOfSteps(budget) => write!(f, "didn't halt within {budget} steps"),
+            Outcome::Cycle { since, step } => {
+                write!(f, "loops forever, step {step} repeats step {since}")
+            }
+        }
+    }
+}
+
+/// Finds cycles in the states after each jump taken, which every loop goes through,
+/// with Brent's algorithm: only the state at the last power of two jumps is kept,
+/// so a long run doesn't remember every state it was in.
+struct Cycles<R> {
+    saved: Option<((R, R, R, usize), usize)>,
+    power: usize,
+    jumps: usize,
+}
+
+impl<R: Register> Cycles<R> {
+    fn new() -> Self {
+        Cycles {
+            saved: None,
+            power: 1,
+            jumps: 0,
+        }
+    }
+
+    /// Looks at `machine` after `step`, which ran the instruction at `ip`, and returns
+    /// the cycle once it is back in a saved state.
+    fn check(&mut self, machine: &Machine<R>, ip: usize, step: usize) -> Option<Outcome> {
+        if machine.ip == ip + 2 {
+            return None;
+        }
+
+        let state = machine.state();
+        if let Some((saved, since)) = &self.saved {
+            if *saved == state {
+                return Some(Outcome::Cycle {
+                    since: *since,
+                    step,
+                });
+            }
+        }
+        self.jumps += 1;
+        if self.jumps == self.power {
+            self.saved = Some((state, step));
+            self.power *= 2;
+            self.jumps = 0;
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine<R = Big> {
+    a: R,
+    b: R,
+    c: R,
+    ip: usize,
+    ops: Vec<usize>,
+    out: Vec<usize>,
+    overflow: Overflow,
+}
+
+impl<R: Register> Machine<R> {
+    fn run(&mut self) -> Result<Outcome, Fault> {
+        self.run_with(STEP_BUDGET, |_| {})
+    }
+
+    /// Runs the program for at most `budget` instructions, calling `before_each`
+    /// before each one, and stops at the first cycle found.
+    fn run_with(
+        &mut self,
+        budget: usize,
+        mut before_each: impl FnMut(&Self),
+    ) -> Result<Outcome, Fault> {
+        let mut cycles = Cycles::new();
+        for step in 1..=budget {
+            before_each(self);
+            let ip = self.ip;
+            if !self.exec()? {
+                return Ok(Outcome::Halted);
+            }
+
+            if let Some(cycle) = cycles.check(self, ip, step) {
+                return Ok(cycle);
+            }
+        }
+
+        match self.next_op() {
+            None => Ok(Outcome::Halted),
+            Some(_) => Ok(Outcome::OutOfSteps(budget)),
+        }
+    }
+
+    /// Everything that decides what the program does next.
+    fn state(&self) -> (R, R, R, usize) {
+        (self.a.clone(), self.b.clone(), self.c.clone(), self.ip)
+    }
+
+    /// The instruction at `ip`, or `None` once the program has halted.
+    fn next_op(&self) -> Option<Op> {
+        let op = *self.ops.get(self.ip)?;
+        let operand = *self.ops.get(self.ip + 1)?;
+        Op::parse(op, operand)
+    }
+
+    /// Runs the next instruction, returning false if the program had already halted.
+    /// A faulting instruction is left as the next one.
+    fn exec(&mut self) -> Result<bool, Fault> {
+        let Some(op) = self.next_op() else {
+            return Ok(false);
+        };
+        let ip = self.ip;
+        self.ip += 2;
+        self.run_op(op, ip).inspect_err(|_| self.ip = ip)?;
+
+        Ok(true)
+    }
+
+    /// The registers and the instruction about to run, or `None` once halted.
+    fn trace(&self) -> Option<String> {
+        let op = self.next_op()?;
+        Some(format!(
+            "{:>3}: {:<6} A={} B={} C={}",
+            self.ip,
+            op.to_string(),
+            self.a,
+            self.b,
+            self.c
+        ))
+    }
+
+    fn get_combo_operand(&self, operand: usize, ip: usize) -> Result<R, Fault> {
+        match operand {
+            op if op <= 3 => Ok(R::from_usize(op)),
+            4 => Ok(self.a.clone()),
+            5 => Ok(self.b.clone()),
+            6 => Ok(self.c.clone()),
+            _ => Err(Fault::ReservedOperand(ip)),
+        }
+    }
+
+    /// A divided by 2 to the power of the combo operand.
+    fn divide(&self, operand: usize, ip: usize) -> Result<R, Fault> {
+        let exponent = self.get_combo_operand(operand, ip)?;
+        let Some(bits) = R::BITS else {
+            return Ok(exponent
+                .to_usize()
+                .map_or_else(|| R::from_usize(0), |by| self.a.shr(by)));
+        };
+
+        match (exponent.to_usize(), self.overflow) {
+            (Some(by), _) if by < bits as usize => Ok(self.a.shr(by)),
+            (_, Overflow::Checked) => Err(Fault::Overflow(ip)),
+            (_, Overflow::Wrapping) => Ok(self.a.shr(exponent.truncate() % bits as usize)),
+        }
+    }
+
+    fn run_op(&mut self, op: Op, ip: usize) -> Result<(), Fault> {
+        match op {
+            Op::Adv(operand) => {
+                self.a = self.divide(operand, ip)?;
+            }
+            Op::Bxl(operand) => {
+                self.b = self.b.xor(&R::from_usize(operand));
+            }
+            Op::Bst(operand) => {
+                let value = self.get_combo_operand(operand, ip)?.truncate() % 8;
+                self.b = R::from_usize(value);
+            }
+            Op::Jnz(operand) => {
+                if !self.a.is_zero() {
+                    self.ip = operand;
+                }
+            }
+            Op::Bxc(_) => {
+                self.b = self.b.xor(&self.c);
+            }
+            Op::Out(operand) => {
+                let value = self.get_combo_operand(operand, ip)?.truncate() % 8;
+                self.out.push(value);
+            }
+            Op::Bdv(operand) => {
+                self.b = self.divide(operand, ip)?;
+            }
+            Op::Cdv(operand) => {
+                self.c = self.divide(operand, ip)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_register<R: Register>(input: &str, line: Option<&str>, name: char) -> parse::Result<R> {
+    let prefix = format!("Register {name}: ");
+    let line = line.ok_or_else(|| ParseError::eof(input, format!("expected `{prefix}`")))?;
+    let value = parse::strip_prefix(input, line, &prefix)?;
+    match R::parse(value) {
+        Some(value) => Ok(value),
+        None if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+            Err(ParseError::at(
+                input,
+                value,
+                format!("`{value}` doesn't fit in the registers"),
+            ))
+        }
+        None => parse::number::<usize>(input, value).map(R::from_usize),
+    }
+}
+
+/// The register widths a `Registers:` header can ask for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Width {
+    U64,
+    U128,
+    Big,
+}
+
+/// Reads the optional header line, such as `Registers: u64 wrapping`, that picks the
+/// width of the registers and what a division that overflows them does. Without one
+/// they are [`Big`]. Returns the rest of the input.
+fn header(input: &str) -> parse::Result<(Width, Overflow, &str)> {
+    let (Some(line), rest) = parse::header(input, "Registers") else {
+        return Ok((Width::Big, Overflow::Checked, input));
+    };
+
+    let mut words = line.split_whitespace();
+    let width = match words.next() {
+        Some("u64") => Width::U64,
+        Some("u128") => Width::U128,
+        Some("big") => Width::Big,
+        other => {
+            return Err(ParseError::at(
+                input,
+                other.unwrap_or(line),
+                "expected a width of `u64`, `u128` or `big`",
+            ))
+        }
+    };
+    let overflow = match words.next() {
+        None | Some("checked") => Overflow::Checked,
+        Some("wrapping") if width != Width::Big => Overflow::Wrapping,
+        Some("wrapping") => {
+            return Err(ParseError::at(
+                input,
+                line,
+                "big registers never overflow, so they can't wrap",
+            ))
+        }
+        Some(other) => {
+            return Err(ParseError::at(
+                input,
+                other,
+                "expected `checked` or `wrapping`",
+            ))
+        }
+    };
+    if let Some(extra) = words.next() {
+        return Err(ParseError::at(input, extra, "expected the end of the line"));
+    }
+
+    Ok((width, overflow, rest))
+}
+
+/// Reads the [`header`], then the registers, then the program either as the puzzle
+/// gives it or as assembly source for [`assemble`]. The registers are `R` whatever
+/// width the header asks for; [`AnyMachine`] follows it.
+fn parse<R: Register>(input: &str) -> parse::Result<Machine<R>> {
+    let (_, overflow, rest) = header(input)?;
+    let (registers, program) = parse::split_once(input, rest, "\n\n")?;
+    let mut registers = registers.lines();
+
+    let a = parse_register(input, registers.next(), 'A')?;
+    let b = parse_register(input, registers.next(), 'B')?;
+    let c = parse_register(input, registers.next(), 'C')?;
+
+    let ops = match program.trim_end().strip_prefix("Program: ") {
+        Some(numbers) => numbers
+            .split(',')
+            .map(|n| {
+                let value = parse::number(input, n.trim())?;
+                if value > 7 {
+                    return Err(ParseError::at(
+                        input,
+                        n.trim(),
+                        format!("expected a 3-bit number, found `{value}`"),
+                    ));
+                }
+                Ok(value)
+            })
+            .collect::<parse::Result<_>>()?,
+        None => assemble(input, program)?,
+    };
+    if ops.is_empty() {
+        return Err(ParseError::eof(input, "expected a program"));
+    }
+
+    Ok(Machine {
+        a,
+        b,
+        c,
+        ip: 0,
+        ops,
+        out: vec![],
+        overflow,
+    })
+}
+
+/// A machine with the registers its input's [`header`] asks for.
+#[derive(Debug, Clone)]
+pub enum AnyMachine {
+    U64(Machine<u64>),
+    U128(Machine<u128>),
+    Big(Machine<Big>),
+}
+
+/// Evaluates `$body` with `$machine` bound to the machine inside an [`AnyMachine`],
+/// whatever its registers.
+macro_rules! with_machine {
+    ($any:expr, $machine:ident => $body:expr) => {
+        match $any {
+            AnyMachine::U64($machine) => $body,
+            AnyMachine::U128($machine) => $body,
+            AnyMachine::Big($machine) => $body,
+        }
+    };
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = AnyMachine;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Ok(match header(input)?.0 {
+            Width::U64 => AnyMachine::U64(parse(input)?),
+            Width::U128 => AnyMachine::U128(parse(input)?),
+            Width::Big => AnyMachine::Big(parse(input)?),
+        })
+    }
+
+    fn part1(&self, machine: &Self::Input) -> Answer {
+        match with_machine!(machine, machine => part1(machine)) {
+            Ok(out) => out.into(),
+            Err(e) => Answer::Failed(e),
+        }
+    }
+
+    fn part2(&self, machine: &Self::Input) -> Answer {
+        match with_machine!(machine, machine => part2(machine)) {
+            Ok(a) => a.map_or(Answer::Unsolved, Answer::from),
+            Err(e) => Answer::Failed(e),
+        }
+    }
+
+    fn explore(&self, machine: &Self::Input, commands: &mut dyn BufRead) -> Option<io::Result<()>> {
+        Some(with_machine!(machine, machine => debug(machine, commands).map(|_| ())))
+    }
+}
+
+/// A command typed while debugging a program.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    /// Run until a breakpoint or the end of the program.
+    Continue,
+    /// Set the breakpoint at an address, or clear it if it is already set.
+    Break(usize),
+    Set(char, usize),
+    List,
+    Trace,
+    Restart,
+    Help,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let number = |word: &str| word.parse().map_err(|_| format!("invalid number '{word}'"));
+
+        match words[..] {
+            [] => Ok(Command::Step(1)),
+            ["s"] => Ok(Command::Step(1)),
+            ["s", n] => Ok(Command::Step(number(n)?)),
+            ["c"] => Ok(Command::Continue),
+            ["b", address] => Ok(Command::Break(number(address)?)),
+            ["set", register, value] => match register {
+                "a" | "A" | "b" | "B" | "c" | "C" => Ok(Command::Set(
+                    register.chars().next().unwrap().to_ascii_uppercase(),
+                    number(value)?,
+                )),
+                _ => Err(format!("unknown register '{register}'")),
+            },
+            ["l"] => Ok(Command::List),
+            ["t"] => Ok(Command::Trace),
+            ["r"] => Ok(Command::Restart),
+            ["h"] | ["?"] => Ok(Command::Help),
+            ["q"] => Ok(Command::Quit),
+            [command, ..] => Err(format!("unknown or incomplete command '{command}'")),
+        }
+    }
+}
+
+const DEBUG_HELP: &str = "Commands:
+    s [N]      Run N instructions (default 1, also an empty line)
+    c          Run until a breakpoint or the end of the program
+    b ADDR     Set or clear a breakpoint at an address
+    set R N    Set register A, B or C to N
+    l          List the program
+    t          Turn tracing each instruction run on or off
+    r          Restart with the starting registers, keeping the breakpoints
+    h          Show this message
+    q          Quit";
+
+/// Steps through the program, driven by `commands`, showing the registers and the
+/// next instruction after each one. Returns the machine as it was left.
+fn debug<R: Register>(start: &Machine<R>, commands: &mut dyn BufRead) -> io::Result<Machine<R>> {
+    let mut machine = start.clone();
+    let mut breakpoints = BTreeSet::new();
+    let mut tracing = false;
+    let mut lines = commands.lines();
+
+    loop {
+        match machine.trace() {
+            Some(line) => eprintln!("{line}"),
+            None => eprintln!(
+                "halted at {}, A={} B={} C={}",
+                machine.ip, machine.a, machine.b, machine.c
+            ),
+        }
+        eprintln!("out: {}", machine.out.iter().join(","));
+        eprint!("ip {}> ", machine.ip);
+
+        let Some(line) = lines.next().transpose()? else {
+            eprintln!();
+            return Ok(machine);
+        };
+
+        let command = Command::parse(&line);
+        let continuing = command == Ok(Command::Continue);
+        let mut steps = match command {
+            Ok(Command::Step(n)) => n,
+            Ok(Command::Continue) => STEP_BUDGET,
+            Ok(Command::Break(address)) => {
+                if !breakpoints.remove(&address) {
+                    breakpoints.insert(address);
+                }
+                0
+            }
+            Ok(Command::Set(register, value)) => {
+                match register {
+                    'A' => machine.a = R::from_usize(value),
+                    'B' => machine.b = R::from_usize(value),
+                    _ => machine.c = R::from_usize(value),
+                }
+                0
+            }
+            Ok(Command::List) => {
+                for (address, instruction) in instructions(&machine.ops) {
+                    let current = if address == machine.ip { '>' } else { ' ' };
+                    let breakpoint = if breakpoints.contains(&address) {
+                        '*'
+                    } else {
+                        ' '
+                    };
+                    eprintln!("{current}{breakpoint}{address:>3}: {instruction}");
+                }
+                0
+            }
+            Ok(Command::Trace) => {
+                tracing = !tracing;
+                eprintln!("tracing {}", if tracing { "on" } else { "off" });
+                0
+            }
+            Ok(Command::Restart) => {
+                machine = start.clone();
+                0
+            }
+            Ok(Command::Help) => {
+                eprintln!("{DEBUG_HELP}");
+                0
+            }
+            Ok(Command::Quit) => return Ok(machine),
+            Err(e) => {
+                eprintln!("{e}\n{DEBUG_HELP}");
+                0
+            }
+        };
+
+        let mut cycles = Cycles::new();
+        let mut step = 0;
+        while steps > 0 {
+            if tracing {
+                if let Some(line) = machine.trace() {
+                    eprintln!("{line}");
+                }
+            }
+            let ip = machine.ip;
+            match machine.exec() {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(fault) => {
+                    eprintln!("{fault}");
+                    break;
+                }
+            }
+            steps -= 1;
+            if breakpoints.contains(&machine.ip) {
+                break;
+            }
+
+            // Continuing stops at a cycle, found as `Machine::run_with` does.
+            step += 1;
+            if continuing {
+                if let Some(cycle) = cycles.check(&machine, ip, step) {
+                    eprintln!("{cycle}");
+                    break;
+                }
+            }
+        }
+        if continuing && steps == 0 {
+            eprintln!("stopped after {STEP_BUDGET} steps");
+        }
+    }
+}
+
+fn part1<R: Register>(machine: &Machine<R>) -> Result<String, String> {
+    let mut machine = machine.clone();
+    if visual::enabled() {
+        eprintln!(
+            "Program: {}\n\n{}\n",
+            machine.ops.iter().join(","),
+            disassemble(&machine.ops)
+        );
+    }
+
+    let traced = visual::enabled();
+    let outcome = machine.run_with(STEP_BUDGET, |machine| {
+        if let Some(line) = traced.then(|| machine.trace()).flatten() {
+            eprintln!("{line}");
+        }
+    });
+    match outcome {
+        Ok(Outcome::Halted) => Ok(machine.out.iter().join(",")),
+        Ok(outcome) => Err(format!("the program {outcome}")),
+        Err(fault) => Err(format!("the program stopped, {fault}")),
+    }
+}
+
+/// One bit of a register in terms of the bits A started with: the XOR of the bits of A
+/// set in `vars`, and of `constant`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bit {
+    vars: u64,
+    constant: bool,
+}
+
+impl Bit {
+    const ZERO: Bit = Bit {
+        vars: 0,
+        constant: false,
+    };
+
+    fn xor(self, other: Bit) -> Bit {
+        Bit {
+            vars: self.vars ^ other.vars,
+            constant: self.constant ^ other.constant,
+        }
+    }
+}
+
+/// A register as bits, least significant first.
+type Word = [Bit; 64];
+
+fn known(n: usize) -> Word {
+    array::from_fn(|i| Bit {
+        vars: 0,
+        constant: n >> i & 1 == 1,
+    })
+}
+
+fn shifted(word: &Word, by: usize) -> Word {
+    array::from_fn(|i| word.get(i + by).copied().unwrap_or(Bit::ZERO))
+}
+
+/// Linear equations over the bits of A, fully reduced so that the pivot bit of each
+/// row appears in no other row.
+#[derive(Debug, Clone, Default)]
+struct Equations {
+    rows: Vec<(u64, Bit)>,
+}
+
+impl Equations {
+    /// Requires `bit` to be `value`, returning false if the equations already rule
+    /// that out.
+    fn add(&mut self, bit: Bit, value: bool) -> bool {
+        let mut bit = Bit {
+            vars: bit.vars,
+            constant: bit.constant ^ value,
+        };
+        for &(pivot, row) in &self.rows {
+            if bit.vars & pivot != 0 {
+                bit = bit.xor(row);
+            }
+        }
+        if bit.vars == 0 {
+            return !bit.constant;
+        }
+
+        let pivot = bit.vars & bit.vars.wrapping_neg();
+        for (_, row) in &mut self.rows {
+            if row.vars & pivot != 0 {
+                *row = row.xor(bit);
+            }
+        }
+        self.rows.push((pivot, bit));
+        true
+    }
+
+    /// The smallest A of `bits` bits that satisfies the equations, choosing each bit
+    /// from the most significant down to be 0 unless it has to be 1.
+    fn smallest(&self, bits: usize) -> usize {
+        let mut equations = self.clone();
+        let mut a = 0;
+        for i in (0..bits).rev() {
+            let bit = Bit {
+                vars: 1 << i,
+                constant: false,
+            };
+            let mut zero = equations.clone();
+            if zero.add(bit, false) {
+                equations = zero;
+            } else {
+                equations.add(bit, true);
+                a |= 1 << i;
+            }
+        }
+        a
+    }
+}
+
+/// The registers while running the loop's body on a symbolic A, with what the outputs
+/// so far require of it.
+#[derive(Debug, Clone)]
+struct Symbolic {
+    a: Word,
+    b: Word,
+    c: Word,
+    equations: Equations,
+    outputs: usize,
+}
+
+impl Symbolic {
+    fn combo(&self, operand: usize) -> Word {
+        match operand {
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            n => known(n),
+        }
+    }
+}
+
+/// Finds the smallest A for which a program shaped as a loop outputs `expected`. The
+/// loop's body must run straight to a final `jnz 0`, with a single `adv 3` as the
+/// only change to A, so that each iteration consumes 3 bits of A and the number of
+/// iterations fixes how many bits A has. The body is run on those bits symbolically:
+/// each output gives linear equations over them, and a shift by a register tries
+/// every value the register may hold.
+fn solve_for<R: Register>(
+    machine: &Machine<R>,
+    expected: &[usize],
+) -> Result<Option<usize>, String> {
+    let (jnz, body) = machine
+        .ops
+        .chunks(2)
+        .map(|pair| match *pair {
+            [op, operand] => Op::parse(op, operand).ok_or("invalid opcode"),
+            _ => Err("the program ends with an opcode without an operand"),
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .split_last()
+        .map(|(&last, body)| (last, body.to_vec()))
+        .ok_or("the program is empty")?;
+
+    if jnz != Op::Jnz(0) {
+        return Err("the program doesn't end with `jnz 0`".to_string());
+    }
+    for op in &body {
+        match op {
+            Op::Jnz(_) => return Err("the loop's body jumps".to_string()),
+            Op::Adv(operand) if *operand != 3 => {
+                return Err(format!("`{op}` changes A by other than 3 bits"))
+            }
+            Op::Bst(7) | Op::Out(7) | Op::Bdv(7) | Op::Cdv(7) => {
+                return Err(format!("`{op}` uses the reserved combo operand 7"))
+            }
+            _ => {}
+        }
+    }
+    if body.iter().filter(|&&op| op == Op::Adv(3)).count() != 1 {
+        return Err("the loop's body needs exactly one `adv 3`".to_string());
+    }
+    let outputs = body.iter().filter(|op| matches!(op, Op::Out(_))).count();
+    if outputs == 0 {
+        return Err("the loop's body has no `out`".to_string());
+    }
+
+    if expected.is_empty() || !expected.len().is_multiple_of(outputs) {
+        return Ok(None);
+    }
+    let iterations = expected.len() / outputs;
+    let bits = 3 * iterations;
+    if bits > 64 {
+        return Err(format!("A would need {bits} bits, more than 64"));
+    }
+
+    let mut search = Search {
+        body,
+        expected,
+        iterations,
+        bits,
+        best: None,
+    };
+    let a = array::from_fn(|i| Bit {
+        vars: if i < bits { 1 << i } else { 0 },
+        constant: false,
+    });
+    let start = Symbolic {
+        a,
+        b: known(register(&machine.b, 'B')?),
+        c: known(register(&machine.c, 'C')?),
+        equations: Equations::default(),
+        outputs: 0,
+    };
+
+    // The loop runs once more for each further 3 bits of A, so the top ones can't all
+    // be 0, except when it runs once.
+    let first = if iterations == 1 { 0 } else { 1 };
+    for top in first..8 {
+        let mut state = start.clone();
+        if (0..3).all(|i| {
+            state
+                .equations
+                .add(start.a[bits - 3 + i], top >> i & 1 == 1)
+        }) {
+            search.run(state, 0)?;
+        }
+    }
+
+    Ok(search.best)
+}
+
+fn register<R: Register>(value: &R, name: char) -> Result<usize, String> {
+    value
+        .to_usize()
+        .ok_or_else(|| format!("{name} doesn't fit in 64 bits"))
+}
+
+struct Search<'a> {
+    body: Vec<Op>,
+    expected: &'a [usize],
+    iterations: usize,
+    bits: usize,
+    best: Option<usize>,
+}
+
+impl Search<'_> {
+    /// Runs the body from instruction `step`, counting on from the first iteration,
+    /// until every iteration is done or the outputs contradict `expected`. Nothing is
+    /// run once the smallest A the equations allow is no better than one already found.
+    fn run(&mut self, mut state: Symbolic, mut step: usize) -> Result<(), String> {
+        if self
+            .best
+            .is_some_and(|best| best <= state.equations.smallest(self.bits))
+        {
+            return Ok(());
+        }
+
+        while step < self.iterations * self.body.len() {
+            let op = self.body[step % self.body.len()];
+            step += 1;
+            let (operand, into_b) = match op {
+                Op::Adv(_) => {
+                    state.a = shifted(&state.a, 3);
+                    continue;
+                }
+                Op::Bxl(literal) => {
+                    state.b = array::from_fn(|i| state.b[i].xor(known(literal)[i]));
+                    continue;
+                }
+                Op::Bst(operand) => {
+                    let value = state.combo(operand);
+                    state.b = array::from_fn(|i| if i < 3 { value[i] } else { Bit::ZERO });
+                    continue;
+                }
+                Op::Bxc(_) => {
+                    state.b = array::from_fn(|i| state.b[i].xor(state.c[i]));
+                    continue;
+                }
+                Op::Out(operand) => {
+                    let value = state.combo(operand);
+                    let expected = self.expected[state.outputs];
+                    if !(0..3).all(|i| state.equations.add(value[i], expected >> i & 1 == 1)) {
+                        return Ok(());
+                    }
+                    state.outputs += 1;
+                    continue;
+                }
+                Op::Bdv(operand) => (operand, true),
+                Op::Cdv(operand) => (operand, false),
+                Op::Jnz(_) => unreachable!(),
+            };
+
+            // A shift by a register: try each value it may hold.
+            let amount = state.combo(operand);
+            let high = amount.iter().rposition(|&bit| bit != Bit::ZERO);
+            let values = match high {
+                None => 1,
+                Some(high) if high < 7 => 1 << (high + 1),
+                Some(_) => return Err(format!("`{op}` shifts by an unbounded amount")),
+            };
+            for value in 0..values {
+                let mut branch = state.clone();
+                if (0..7).all(|i| branch.equations.add(amount[i], value >> i & 1 == 1)) {
+                    let result = shifted(&branch.a, value);
+                    if into_b {
+                        branch.b = result;
+                    } else {
+                        branch.c = result;
+                    }
+                    self.run(branch, step)?;
+                }
+            }
+            return Ok(());
+        }
+
+        let a = state.equations.smallest(self.bits);
+        self.best = Some(self.best.map_or(a, |best| best.min(a)));
+        Ok(())
+    }
+}
+
+/// The smallest A for which the program outputs itself, checked by running it.
+fn part2<R: Register>(machine: &Machine<R>) -> Result<Option<usize>, String> {
+    let Some(a) = solve_for(machine, &machine.ops)? else {
+        return Ok(None);
+    };
+
+    let mut quine = machine.clone();
+    quine.a = R::from_usize(a);
+    match quine.run() {
+        Ok(Outcome::Halted) if quine.out == quine.ops => Ok(Some(a)),
+        Ok(Outcome::Halted) => Err(format!(
+            "A={a} outputs {} instead",
+            quine.out.iter().join(",")
+        )),
+        Ok(outcome) => Err(format!("with A={a} the program {outcome}")),
+        Err(fault) => Err(format!("with A={a} the program stopped, {fault}")),
+    }
+}
+
+#[cfg(test)]
+mod day17_test {
+    use std::io::Cursor;
+
+    use super::{
+        assemble, debug, disassemble, parse, part1, part2, solve_for, AnyMachine, Big, Command,
+        Day17, Fault, Machine, Outcome, Overflow, Register,
+    };
+    use crate::days::prop::{self, Rng};
+    use crate::days::{Answer, Solution};
+
+    const EXAMPLE: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            disassemble(&[0, 1, 5, 4, 3, 0]),
+            "  0: adv 1\n  2: out A\n  4: jnz 0"
+        );
+        assert_eq!(
+            disassemble(&[2, 6, 1, 7, 4, 0, 4, 3, 7, 5, 6]),
+            "  0: bst C\n  2: bxl 7\n  4: bxc\n  6: bxc 3\n  8: cdv B\n 10: 6"
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let machine: Machine = parse(EXAMPLE).unwrap();
+        assert_eq!(machine.trace().unwrap(), "  0: adv 1  A=729 B=0 C=0");
+    }
+
+    #[test]
+    fn test_commands() {
+        assert_eq!(Command::parse(""), Ok(Command::Step(1)));
+        assert_eq!(Command::parse("s 10"), Ok(Command::Step(10)));
+        assert_eq!(Command::parse("b 4"), Ok(Command::Break(4)));
+        assert_eq!(Command::parse("set a 7"), Ok(Command::Set('A', 7)));
+        assert!(Command::parse("b").is_err());
+        assert!(Command::parse("set d 1").is_err());
+        assert!(Command::parse("s many").is_err());
+    }
+
+    #[test]
+    fn test_debug() {
+        let machine: Machine = parse(EXAMPLE).unwrap();
+
+        // Stops at the breakpoint on `jnz` every time round the loop.
+        let mut commands = Cursor::new("b 4\nc\nc\n");
+        let stopped = debug(&machine, &mut commands).unwrap();
+        assert_eq!((stopped.ip, stopped.out.clone()), (4, vec![4, 6]));
+
+        let mut commands = Cursor::new("set a 0\nc\nwat\nq\ns\n");
+        let halted = debug(&machine, &mut commands).unwrap();
+        assert_eq!((halted.ip, halted.out.clone()), (6, vec![0]));
+
+        let mut commands = Cursor::new("s 3\nr\nl\nt\ns\n");
+        let restarted = debug(&machine, &mut commands).unwrap();
+        assert_eq!((restarted.ip, restarted.a), (2, Big::from_usize(364)));
+    }
+
+    #[test]
+    fn test_assemble() {
+        let source = "
+start:  adv 1   ; halve A
+        out a
+        jnz start
+";
+        assert_eq!(assemble(source, source).unwrap(), [0, 1, 5, 4, 3, 0]);
+        assert_eq!(
+            assemble("bxc\nbxc 3\nBST C\n6", "bxc\nbxc 3\nBST C\n6").unwrap(),
+            [4, 0, 4, 3, 2, 6, 6]
+        );
+
+        let errors = [
+            (
+                "adv 7",
+                "line 1, column 5: expected 0 to 3, A, B or C, found `7`",
+            ),
+            (
+                "bxl 8",
+                "line 1, column 5: expected a 3-bit number, found `8`",
+            ),
+            ("out", "line 1, column 1: `out` needs an operand"),
+            (
+                "mul 2",
+                "line 1, column 1: expected an instruction, found `mul`",
+            ),
+            ("jnz end", "line 1, column 5: unknown label `end`"),
+            (
+                "a: out a\na: out b",
+                "line 2, column 1: label `a` is defined twice",
+            ),
+            (
+                "0: adv 1\n4: out a",
+                "line 2, column 1: expected address 2, found 4",
+            ),
+            (
+                "adv 1\nadv 1\nadv 1\nadv 1\nend: jnz end",
+                "line 5, column 10: label `end` is at 8, out of reach of `jnz`",
+            ),
+        ];
+        for (source, error) in errors {
+            let found = assemble(source, source).unwrap_err();
+            assert_eq!(found.to_string(), error, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_parse_source() {
+        let machine = parse::<u64>(
+            "Register A: 729\nRegister B: 0\nRegister C: 0\n\nadv 1\n\nout A\njnz 0\n",
+        )
+        .unwrap();
+        assert_eq!(machine.ops, [0, 1, 5, 4, 3, 0]);
+    }
+
+    /// A program of whole instructions, none with the reserved combo operand 7.
+    fn program(rng: &mut Rng) -> Vec<usize> {
+        rng.vec(1..=16, |rng| {
+            let op = rng.range(0..=7);
+            let operand = match op {
+                1 | 3 | 4 => rng.range(0..=7),
+                _ => rng.range(0..=6),
+            };
+            [op, operand]
+        })
+        .concat()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        prop::check(program, |ops| {
+            let source = disassemble(ops);
+            assert_eq!(&assemble(&source, &source).unwrap(), ops);
+        });
+    }
+
+    #[test]
+    fn test_quine() {
+        let source = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,1,7,5,1,5,4,0,5,5,0,3,3,0\n";
+        let machine = parse(source).unwrap();
+        assert_eq!(part2(&machine), Ok(Some(164279024971453)));
+
+        let mut quine = machine.clone();
+        quine.a = Big::from_usize(164279024971453);
+        quine.run().unwrap();
+        assert_eq!(quine.out, quine.ops);
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let errors = [
+            ("adv 3\nout a", "the program doesn't end with `jnz 0`"),
+            ("adv 3\njnz 0\nout a\njnz 0", "the loop's body jumps"),
+            (
+                "adv 2\nout a\njnz 0",
+                "`adv 2` changes A by other than 3 bits",
+            ),
+            ("adv 3\nbst a\njnz 0", "the loop's body has no `out`"),
+            ("out a\njnz 0", "the loop's body needs exactly one `adv 3`"),
+            (
+                "adv 3\nbdv 1\ncdv b\nout c\njnz 0",
+                "`cdv B` shifts by an unbounded amount",
+            ),
+        ];
+        for (program, error) in errors {
+            let source = format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\n{program}\n");
+            let machine: Machine = parse(&source).unwrap();
+            assert_eq!(part2(&machine), Err(error.to_string()), "{program}");
+        }
+    }
+
+    /// A loop consuming 3 bits of A with a few other instructions around the output,
+    /// shifting only by literals or by B straight after `bst`, so that it never shifts
+    /// by more than 7. Comes with what it outputs for an A of 1 to 4 chunks of 3 bits.
+    fn looping(rng: &mut Rng) -> (Machine<u64>, Vec<usize>) {
+        let combo = |rng: &mut Rng| rng.range(0..=6);
+        let mut blocks = vec![vec![0, 3], vec![5, combo(rng)]];
+        for _ in 0..rng.range(0..=4) {
+            blocks.push(match rng.range(0..=4) {
+                0 => vec![1, rng.range(0..=7)],
+                1 => vec![2, combo(rng)],
+                2 => vec![4, rng.range(0..=7)],
+                3 => vec![2, combo(rng), 6 + rng.range(0..=1), 5],
+                _ => vec![6 + rng.range(0..=1), rng.range(0..=3)],
+            });
+        }
+        rng.shuffle(&mut blocks);
+        blocks.push(vec![3, 0]);
+
+        let mut machine = Machine {
+            a: 0,
+            b: rng.range(0..=7) as u64,
+            c: rng.range(0..=7) as u64,
+            ip: 0,
+            ops: blocks.concat(),
+            out: vec![],
+            overflow: Overflow::Checked,
+        };
+        let chunks = rng.range(1..=4);
+        let mut run = machine.clone();
+        run.a = rng.range(8usize.pow(chunks as u32 - 1)..=8usize.pow(chunks as u32) - 1) as u64;
+        run.run().unwrap();
+        machine.out = vec![];
+        (machine, run.out)
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        prop::check(looping, |(machine, expected)| {
+            let smallest = (0..8usize.pow(4)).find(|&a| {
+                let mut run = machine.clone();
+                run.a = a as u64;
+                run.run().unwrap();
+                &run.out == expected
+            });
+            assert_eq!(solve_for(machine, expected), Ok(smallest));
+        });
+    }
+
+    const WIDE: &str = "Register A: 1267650600228229401496703205376
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn test_width() {
+        // A is 2^100, which takes 34 octal digits.
+        let mut wide = parse::<u128>(WIDE).unwrap();
+        wide.run().unwrap();
+        let mut big = parse::<Big>(WIDE).unwrap();
+        big.run().unwrap();
+        assert_eq!(wide.out.len(), 34);
+        assert_eq!(big.out, wide.out);
+
+        let error = parse::<u64>(WIDE).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 13: `1267650600228229401496703205376` doesn't fit in the registers"
+        );
+    }
+
+    #[test]
+    fn test_faults() {
+        let mut reserved =
+            parse::<u64>("Register A: 1\nRegister B: 0\nRegister C: 0\n\nbxl 1\n5\n7").unwrap();
+        assert_eq!(reserved.run(), Err(Fault::ReservedOperand(2)));
+        assert_eq!(reserved.ip, 2);
+
+        // Dividing by 2^70 overflows 64 bits, unless the exponent wraps to 6.
+        let source = "Register A: 1000\nRegister B: 0\nRegister C: 70\n\nadv c\nout a\n";
+        let mut checked = parse::<u64>(source).unwrap();
+        assert_eq!(checked.run(), Err(Fault::Overflow(0)));
+        let mut wrapping = parse::<u64>(&format!("Registers: u64 wrapping\n{source}")).unwrap();
+        wrapping.run().unwrap();
+        assert_eq!((wrapping.a, wrapping.out.clone()), (15, vec![7]));
+        let mut wide = parse::<u128>(source).unwrap();
+        wide.run().unwrap();
+        assert_eq!(wide.a, 0);
+        let mut big = parse::<Big>(source).unwrap();
+        big.run().unwrap();
+        assert!(big.a.is_zero());
+    }
+
+    #[test]
+    fn test_header() {
+        let source = "Register A: 1000\nRegister B: 0\nRegister C: 70\n\nadv c\nout a\n";
+        let with = |header: &str| Day17.parse(&format!("{header}{source}"));
+        assert!(matches!(with(""), Ok(AnyMachine::Big(_))));
+        assert!(matches!(with("Registers: u128\n"), Ok(AnyMachine::U128(_))));
+        match with("Registers: u64 wrapping\n") {
+            Ok(AnyMachine::U64(machine)) => assert_eq!(machine.overflow, Overflow::Wrapping),
+            other => panic!("{other:?}"),
+        }
+        assert_eq!(
+            Day17.part1(&with("Registers: u64 wrapping\n").unwrap()),
+            Answer::from("7".to_string())
+        );
+        assert_eq!(
+            Day17.part1(&with("Registers: u64\n").unwrap()),
+            Answer::Failed(
+                "the program stopped, division at 0 overflows the registers".to_string()
+            )
+        );
+
+        for (header, error) in [
+            (
+                "Registers: u32\n",
+                "line 1, column 12: expected a width of `u64`, `u128` or `big`",
+            ),
+            (
+                "Registers: u64 saturating\n",
+                "line 1, column 16: expected `checked` or `wrapping`",
+            ),
+            (
+                "Registers: big wrapping\n",
+                "line 1, column 12: big registers never overflow, so they can't wrap",
+            ),
+            (
+                "Registers: u64 checked twice\n",
+                "line 1, column 24: expected the end of the line",
+            ),
+        ] {
+            assert_eq!(with(header).unwrap_err().to_string(), error, "{header}");
+        }
+    }
+
+    #[test]
+    fn test_big() {
+        let n = "340282366920938463463374607431768211457123456789";
+        let big = Big::parse(n).unwrap();
+        assert_eq!(big.to_string(), n);
+        assert_eq!(Big::parse("0").unwrap().to_string(), "0");
+        assert_eq!(Big::parse("12a"), None);
+        assert_eq!(big.to_usize(), None);
+
+        // Shifting and XOR agree with u128 where it fits.
+        let n = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321_u128;
+        let big = Big::parse(&n.to_string()).unwrap();
+        for by in [0, 1, 63, 64, 65, 100, 127, 128, 500] {
+            assert_eq!(big.shr(by).to_string(), n.shr(by).to_string(), "{by}");
+        }
+        let other = Big::parse(&u64::MAX.to_string()).unwrap();
+        assert_eq!(
+            big.xor(&other).to_string(),
+            (n ^ u64::MAX as u128).to_string()
+        );
+        assert_eq!(big.truncate(), n as usize);
+    }
+
+    #[test]
+    fn test_budget() {
+        let mut machine = parse::<u64>(EXAMPLE).unwrap();
+        assert_eq!(machine.run_with(5, |_| {}), Ok(Outcome::OutOfSteps(5)));
+        assert_eq!(machine.run_with(100, |_| {}), Ok(Outcome::Halted));
+        assert_eq!(machine.out, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+        // Exactly enough steps to halt.
+        let mut machine = parse::<u64>(EXAMPLE).unwrap();
+        let mut steps = 0;
+        assert_eq!(machine.run_with(30, |_| steps += 1), Ok(Outcome::Halted));
+        assert_eq!(steps, 30);
+    }
+
+    #[test]
+    fn test_cycle() {
+        // B toggles between 0 and 1 while A never changes.
+        let source =
+            "Register A: 5\nRegister B: 0\nRegister C: 0\n\nloop: bxl 1\nout b\njnz loop\n";
+        let mut machine = parse::<u64>(source).unwrap();
+        assert_eq!(machine.run(), Ok(Outcome::Cycle { since: 3, step: 9 }));
+        assert_eq!(machine.out, [1, 0, 1]);
+
+        let machine: Machine = parse(source).unwrap();
+        let stopped = debug(&machine, &mut Cursor::new("c\n")).unwrap();
+        assert_eq!(stopped.out, [1, 0, 1]);
+        assert_eq!(
+            part1(&machine),
+            Err("the program loops forever, step 9 repeats step 3".to_string())
+        );
+    }
+}
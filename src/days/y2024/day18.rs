@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use crate::days::{
+    grid::{Grid, Point},
+    parse, search,
+    visual::{self, Canvas, Color},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Node {
+    Free,
+    Corrupted,
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Free => write!(f, "."),
+            Node::Corrupted => write!(f, "#"),
+        }
+    }
+}
+
+const PALETTE: &[(char, Color)] = &[('#', Color::Gray)];
+
+fn draw(grid: &Grid<Node>, path: &[Point], blocker: Option<Point>) {
+    let mut canvas = Canvas::from_display(grid);
+    canvas
+        .palette(PALETTE)
+        .overlay(path.iter().copied(), 'O', Color::Green);
+    let caption = match blocker {
+        Some(p) => format!("Blocked by the byte at {p}"),
+        None => format!("Shortest path: {} steps", path.len().saturating_sub(1)),
+    };
+    if let Some(p) = blocker {
+        canvas.set(p, '#', Color::Red);
+    }
+
+    visual::show(&canvas, &caption);
+}
+
+fn add_corrupted_nodes(grid: &mut Grid<Node>, nodes: &[Point]) {
+    for &node in nodes {
+        grid[node] = Node::Corrupted;
+    }
+}
+
+fn end(grid: &Grid<Node>) -> Point {
+    Point::new(grid.width() as isize - 1, grid.height() as isize - 1)
+}
+
+/// The size of the memory space and how many bytes have fallen for part 1, unless the
+/// input's `Size` and `Bytes` headers say otherwise.
+const SIZE: (usize, usize) = (71, 71);
+const P1_CUTOFF: usize = 1024;
+
+/// The bytes falling into a memory space of `size`, the first `cutoff` of which have
+/// fallen for part 1.
+#[derive(Debug)]
+pub struct Memory {
+    size: (usize, usize),
+    cutoff: usize,
+    nodes: Vec<Point>,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        get_nodes_from_input(input)
+    }
+
+    fn part1(&self, memory: &Self::Input) -> Answer {
+        let grid = parse(&memory.nodes[..memory.cutoff], memory.size);
+        let Some(path) = bfs(&grid) else {
+            return Answer::Unsolved;
+        };
+        if visual::enabled() {
+            draw(&grid, &path, None);
+        }
+        (path.len() - 1).into()
+    }
+
+    fn part2(&self, memory: &Self::Input) -> Answer {
+        let mut grid = parse(&memory.nodes[..memory.cutoff], memory.size);
+        let Some(p1) = bfs(&grid) else {
+            return Answer::Unsolved;
+        };
+        part2(&mut grid, &memory.nodes[memory.cutoff..], p1)
+            .map_or(Answer::Unsolved, |p| p.to_string().into())
+    }
+}
+
+fn part2(
+    grid: &mut Grid<Node>,
+    mut remaining_nodes: &[Point],
+    initial_path: Vec<Point>,
+) -> Option<Point> {
+    let mut path_nodes: HashSet<Point> = HashSet::from_iter(initial_path.iter().copied());
+    let mut path = initial_path;
+
+    'outer: loop {
+        for (i, &cur_node) in remaining_nodes.iter().enumerate() {
+            if path_nodes.contains(&cur_node) {
+                add_corrupted_nodes(grid, &remaining_nodes[0..=i]);
+                match bfs(grid) {
+                    Some(new_path) => {
+                        path_nodes = HashSet::from_iter(new_path.iter().copied());
+                        path = new_path;
+                        remaining_nodes = &remaining_nodes[i + 1..];
+                        continue 'outer;
+                    }
+                    None => {
+                        if visual::enabled() {
+                            draw(grid, &path, Some(cur_node));
+                        }
+                        return Some(cur_node);
+                    }
+                }
+            };
+        }
+
+        return None;
+    }
+}
+
+/// Reads the coordinates of the falling bytes, after optional `Size: <w>x<h>` and
+/// `Bytes: <n>` headers for the examples, which are smaller than the real memory.
+fn get_nodes_from_input(input: &str) -> parse::Result<Memory> {
+    let (size, rest) = parse::header(input, "Size");
+    let size = size.map_or(Ok(SIZE), |size| parse::size(input, size))?;
+    let (cutoff, rest) = parse::header(rest, "Bytes");
+    let cutoff = cutoff.map_or(Ok(P1_CUTOFF), |n| parse::number(input, n))?;
+
+    let nodes = rest
+        .lines()
+        .map(|l| {
+            let (x, y) = parse::split_once(input, l, ",")?;
+            let coordinate = |s, below: usize| {
+                let n: usize = parse::number(input, s)?;
+                if n >= below {
+                    return Err(ParseError::at(
+                        input,
+                        s,
+                        format!("expected a coordinate below {below}, found `{n}`"),
+                    ));
+                }
+                Ok(n as isize)
+            };
+
+            Ok(Point::new(coordinate(x, size.0)?, coordinate(y, size.1)?))
+        })
+        .collect::<parse::Result<Vec<_>>>()?;
+
+    if nodes.len() < cutoff {
+        return Err(ParseError::eof(
+            input,
+            format!("expected at least {cutoff} bytes, found {}", nodes.len()),
+        ));
+    }
+
+    Ok(Memory {
+        size,
+        cutoff,
+        nodes,
+    })
+}
+
+fn parse(occupied_nodes: &[Point], (width, height): (usize, usize)) -> Grid<Node> {
+    let mut grid = Grid::new(width, height, Node::Free);
+    add_corrupted_nodes(&mut grid, occupied_nodes);
+    grid
+}
+
+/// A shortest path from the top left to the bottom right corner, both included.
+fn bfs(grid: &Grid<Node>) -> Option<Vec<Point>> {
+    let free = |p: &Point| grid[*p] == Node::Free;
+    let start = Point::new(0, 0);
+    if !free(&start) {
+        return None;
+    }
+
+    let dest = end(grid);
+    search::bfs_to(start, |&p| grid.neighbors(p).filter(free), |&p| p == dest)
+}
+
+#[cfg(test)]
+mod day18_test {
+    use super::Day18;
+    use crate::days::{Answer, Solution};
+
+    #[test]
+    fn test_headers() {
+        let memory = Day18.parse("Size: 3x2\nBytes: 1\n1,0\n1,1\n").unwrap();
+        assert_eq!(
+            (memory.size, memory.cutoff, memory.nodes.len()),
+            ((3, 2), 1, 2)
+        );
+        assert_eq!(Day18.part1(&memory), Answer::Int(3));
+        assert_eq!(Day18.part2(&memory), Answer::Text("1,1".to_string()));
+
+        for (input, error) in [
+            (
+                "Size: 3x2\n3,0\n",
+                "line 2, column 1: expected a coordinate below 3, found `3`",
+            ),
+            (
+                "Size: 3x2\nBytes: 2\n0,1\n",
+                "line 4, column 1: expected at least 2 bytes, found 1",
+            ),
+            ("Size: 3\n", "line 1, column 7: expected `x`"),
+            (
+                "0,0\n",
+                "line 2, column 1: expected at least 1024 bytes, found 1",
+            ),
+        ] {
+            assert_eq!(
+                Day18.parse(input).unwrap_err().to_string(),
+                error,
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::days::{parallel, parse, Answer, Solution};
+
+#[derive(Debug)]
+struct Node {
+    #[allow(dead_code)]
+    value: String,
+    non_leaf_included: bool,
+    children: HashMap<char, Node>,
+}
+
+impl Node {
+    fn new(value: &str) -> Node {
+        Node {
+            value: value.to_string(),
+            non_leaf_included: false,
+            children: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Trie {
+    head: Node,
+}
+
+impl Trie {
+    fn new() -> Self {
+        Self {
+            head: Node::new(""),
+        }
+    }
+
+    fn insert(&mut self, pattern: &str) {
+        let mut cur_node = &mut self.head;
+        for (i, c) in pattern.char_indices() {
+            cur_node = cur_node
+                .children
+                .entry(c)
+                .or_insert_with(|| Node::new(&pattern[..=i]));
+            if i == pattern.len() - 1 {
+                cur_node.non_leaf_included = true;
+            }
+        }
+    }
+
+    fn contains(&self, pattern: &str) -> (bool, bool) {
+        let mut cur_node = &self.head;
+        for c in pattern.chars() {
+            match cur_node.children.get(&c) {
+                Some(child) => cur_node = child,
+                None => return (false, false),
+            }
+        }
+
+        (
+            cur_node.children.is_empty() || cur_node.non_leaf_included,
+            !cur_node.children.is_empty(),
+        )
+    }
+
+    fn contains_subpatterns(&self, p: &str) -> bool {
+        let mut stack = Vec::new();
+        stack.push(p);
+
+        while let Some(p) = stack.pop() {
+            for i in 1..=p.len() {
+                let pp = &p[..i];
+                let rem = &p[i..];
+                let (contained, is_prefix) = self.contains(pp);
+                if !contained && !is_prefix {
+                    break;
+                }
+                if contained {
+                    if rem.is_empty() {
+                        return true;
+                    }
+                    stack.push(rem);
+                }
+                if !is_prefix {
+                    break;
+                }
+            }
+        }
+        false
+    }
+}
+
+fn test<'a>(patterns: &[String], p: &'a str, cache: &mut HashMap<&'a str, usize>) -> usize {
+    if p.is_empty() {
+        return 1;
+    }
+
+    if let Some(&c) = cache.get(p) {
+        return c;
+    }
+
+    let mut count = 0;
+    for pattern in patterns {
+        if p.starts_with(pattern) {
+            count += test(patterns, &p[pattern.len()..], cache);
+        }
+    }
+
+    cache.insert(p, count);
+    count
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Trie, Vec<String>, Vec<String>);
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, (trie, _, possibilities): &Self::Input) -> Answer {
+        part1(trie, possibilities).into()
+    }
+
+    fn part2(&self, (_, patterns, possibilities): &Self::Input) -> Answer {
+        part2(patterns, possibilities).into()
+    }
+}
+
+fn parse(input: &str) -> parse::Result<(Trie, Vec<String>, Vec<String>)> {
+    let mut trie = Trie::new();
+    let [f, designs] = parse::sections(input)?;
+
+    let patterns = f.split(", ").map(String::from).collect_vec();
+    for pattern in &patterns {
+        trie.insert(pattern);
+    }
+
+    let possibilities = designs.lines().map(String::from).collect_vec();
+
+    Ok((trie, patterns, possibilities))
+}
+
+fn part1(trie: &Trie, patterns: &[String]) -> usize {
+    parallel::map(patterns, |p| trie.contains_subpatterns(p))
+        .into_iter()
+        .filter(|&possible| possible)
+        .count()
+}
+
+fn part2(prefixes: &[String], patterns: &[String]) -> usize {
+    parallel::map(patterns, |p| test(prefixes, p, &mut HashMap::new()))
+        .into_iter()
+        .sum()
+}
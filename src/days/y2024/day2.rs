@@ -1,10 +1,15 @@
 use std::collections::{HashSet, VecDeque};
 
-struct Report(Vec<usize>);
+use crate::days::{parse, Answer, Solution};
+
+pub struct Report(Vec<usize>);
 
 impl Report {
-    fn from_line(line: &str) -> Self {
-        Self(line.split(' ').map(|elem| elem.parse().unwrap()).collect())
+    fn from_line(input: &str, line: &str) -> parse::Result<Self> {
+        line.split(' ')
+            .map(|elem| parse::number(input, elem))
+            .collect::<parse::Result<_>>()
+            .map(Self)
     }
 
     fn is_safe(&self) -> bool {
@@ -39,7 +44,7 @@ impl Report {
         let mut first = true;
         let report = &self.0;
         let mut is_valid = false;
-        'outer: while first || queue.len() > 0 {
+        'outer: while first || !queue.is_empty() {
             first = false;
             let i_to_ignore = queue.pop_front();
 
@@ -114,17 +119,32 @@ impl Report {
     }
 }
 
-pub fn day2(input: String) {
-    let reports = input.lines().map(|line| Report::from_line(line)).collect();
-    println!("Part 1: {}", part1(&reports));
-    println!("Part 2: {}", part2(&reports));
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Report>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| Report::from_line(input, line))
+            .collect()
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Answer {
+        part1(reports).into()
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Answer {
+        part2(reports).into()
+    }
 }
 
-fn part1(reports: &Vec<Report>) -> usize {
+fn part1(reports: &[Report]) -> usize {
     reports.iter().filter(|r| r.is_safe()).count()
 }
 
-fn part2(reports: &Vec<Report>) -> usize {
+fn part2(reports: &[Report]) -> usize {
     reports.iter().filter(|r| r.is_safe_dampened()).count()
 }
 
@@ -134,18 +154,9 @@ mod day2_tests {
 
     #[test]
     fn part2_edge_cases() {
-        assert_eq!(
-            Report(vec![19, 20, 21, 22, 23, 25, 26, 30]).is_safe_dampened(),
-            true
-        );
-        assert_eq!(
-            Report(vec![40, 41, 41, 43, 45, 43]).is_safe_dampened(),
-            false
-        );
-
-        assert_eq!(
-            Report(vec![43, 40, 41, 44, 45, 46, 48, 51]).is_safe_dampened(),
-            true
-        );
+        assert!(Report(vec![19, 20, 21, 22, 23, 25, 26, 30]).is_safe_dampened());
+        assert!(!Report(vec![40, 41, 41, 43, 45, 43]).is_safe_dampened());
+
+        assert!(Report(vec![43, 40, 41, 44, 45, 46, 48, 51]).is_safe_dampened());
     }
 }
@@ -0,0 +1,217 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::days::{
+    grid::{Direction, Grid, Point},
+    parallel, parse, search,
+    visual::{self, Canvas, Color},
+    Answer, Solution,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Start,
+    End,
+    Free,
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Free),
+            '#' => Some(Self::Wall),
+            'S' => Some(Self::Start),
+            'E' => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+const PALETTE: &[(char, Color)] = &[
+    ('#', Color::Gray),
+    ('S', Color::Yellow),
+    ('E', Color::Yellow),
+];
+
+/// How many picoseconds a cheat must save to count, unless the input's `Threshold`
+/// header says otherwise.
+const THRESHOLD: usize = 100;
+
+#[derive(Debug)]
+pub struct Track {
+    tiles: Grid<Tile>,
+    start: Point,
+    end: Point,
+    threshold: usize,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+struct Cheat(Point, Point);
+
+impl Track {
+    /// Reads the track after an optional `Threshold: <n>` header for the examples,
+    /// whose cheats save less time.
+    fn parse(input: &str) -> parse::Result<Track> {
+        let (threshold, map) = parse::header(input, "Threshold");
+        let threshold = threshold.map_or(Ok(THRESHOLD), |n| parse::number(input, n))?;
+        let tiles = Grid::parse(map, Tile::new)?;
+        let start = tiles.find_one(map, "start", |t| *t == Tile::Start)?;
+        let end = tiles.find_one(map, "end", |t| *t == Tile::End)?;
+
+        Ok(Track {
+            tiles,
+            start,
+            end,
+            threshold,
+        })
+    }
+
+    /// Draws the race without cheating, with the tiles where cheats can start in red.
+    fn draw_cheats(&self, cheats: &[Cheat], path: &[Point]) {
+        let symbols = self.tiles.map(|tile| match tile {
+            Tile::End => 'E',
+            Tile::Wall => '#',
+            Tile::Free => '.',
+            Tile::Start => 'S',
+        });
+        let mut canvas = Canvas::new(&symbols);
+        canvas
+            .palette(PALETTE)
+            .overlay(path.iter().copied(), 'O', Color::Green)
+            .highlight(cheats.iter().map(|c| c.0), Color::Red);
+
+        visual::show(
+            &canvas,
+            &format!(
+                "{} possible cheats along {} steps",
+                cheats.len(),
+                path.len()
+            ),
+        );
+    }
+
+    fn is_valid(
+        &self,
+        pos_before_cheat: Point,
+        initial_pos: Point,
+        next_pos: Point,
+        initial_path: &[Point],
+        distances: &HashMap<Point, usize>,
+    ) -> bool {
+        if self.tiles[next_pos] == Tile::Wall {
+            return false;
+        }
+        // Only the first open tile in this order is considered.
+        let order = [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ];
+        for next_next in order
+            .map(|dir| next_pos.step(dir))
+            .into_iter()
+            .filter(|&p| self.tiles.contains(p))
+        {
+            if self.tiles[next_next] != Tile::Wall && next_next != initial_pos {
+                let dist_to_pos = *distances.get(&pos_before_cheat).unwrap_or(&usize::MAX);
+                let dist_to_cheat_end = *distances.get(&next_next).unwrap_or(&usize::MAX);
+                return initial_path.contains(&next_next) && dist_to_cheat_end >= dist_to_pos + 2;
+            }
+        }
+
+        false
+    }
+
+    fn cheats_from_initial_path(
+        &self,
+        path: &[Point],
+        distances: &HashMap<Point, usize>,
+    ) -> Vec<Cheat> {
+        let mut cheats = HashSet::new();
+        for node in path {
+            for c in self.cheats_from_pos(*node, path, distances) {
+                cheats.insert(c);
+            }
+        }
+
+        cheats.into_iter().collect_vec()
+    }
+
+    fn cheats_from_pos(
+        &self,
+        pos: Point,
+        path: &[Point],
+        distances: &HashMap<Point, usize>,
+    ) -> Vec<Cheat> {
+        let mut cheats = vec![];
+        for cheat_start in self.tiles.neighbors(pos) {
+            for cheat_end in self.tiles.neighbors(cheat_start) {
+                if self.is_valid(pos, cheat_start, cheat_end, path, distances) {
+                    let cheat = Cheat(cheat_start, cheat_end);
+                    if !cheats.contains(&cheat) {
+                        cheats.push(cheat);
+                    }
+                }
+            }
+        }
+
+        cheats
+    }
+
+    /// The tiles reachable in one step from `pos`. With a cheat, its first tile can be
+    /// entered even if it is a wall, and it must be left for the second tile.
+    fn moves(&self, cheat: Option<&Cheat>, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        let cheat = cheat.cloned();
+        self.tiles.neighbors(pos).filter(move |&next| match &cheat {
+            Some(Cheat(c1, c2)) if pos == *c1 => next == *c2,
+            Some(Cheat(c1, _)) if next == *c1 => true,
+            _ => self.tiles[next] != Tile::Wall,
+        })
+    }
+
+    /// The shortest race from start to end, both included.
+    fn race(&self, cheat: Option<&Cheat>) -> Option<Vec<Point>> {
+        search::bfs_to(self.start, |&p| self.moves(cheat, p), |&p| p == self.end)
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Track;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Track::parse(input)
+    }
+
+    fn part1(&self, track: &Self::Input) -> Answer {
+        part1(track).into()
+    }
+}
+
+fn part1(track: &Track) -> usize {
+    let Some(race) = track.race(None) else {
+        return 0;
+    };
+    let distances = search::bfs(track.start, |&p| track.moves(None, p));
+    let no_cheats_path = &race[1..];
+    let cheats = track.cheats_from_initial_path(no_cheats_path, distances.distances());
+    let start_cost = no_cheats_path.len();
+    if visual::enabled() {
+        eprintln!("Initial cost: {start_cost}");
+        eprintln!("Possible cheats: {}", cheats.len());
+        track.draw_cheats(&cheats, no_cheats_path);
+    }
+    let savings = parallel::map(&cheats, |cheat| {
+        let n = track.race(Some(cheat)).map_or(0, |race| race.len() - 1);
+        let diff = start_cost.saturating_sub(n);
+        (diff > 0 && n > 0).then_some(diff)
+    });
+
+    savings
+        .into_iter()
+        .filter(|&diff| diff.is_some_and(|d| d >= track.threshold))
+        .count()
+}
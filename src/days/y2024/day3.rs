@@ -1,3 +1,6 @@
+use crate::days::{parse, Answer, Solution};
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Token {
     MUL,
@@ -26,7 +29,7 @@ struct Lexer {
 }
 
 impl Lexer {
-    fn new(input: &String) -> Self {
+    fn new(input: &str) -> Self {
         Self {
             text: input.chars().collect(),
             pos: 0,
@@ -34,54 +37,44 @@ impl Lexer {
     }
 
     fn get_mul(&mut self) -> Token {
-        let buf = &self.text[self.pos..self.pos + 3];
-        let token = match buf {
-            ['m', 'u', 'l'] => Token::MUL,
-            _ => Token::GARBAGE,
-        };
-
-        self.pos += 2;
-        token
+        if self.text[self.pos..].starts_with(&['m', 'u', 'l']) {
+            self.pos += 2;
+            Token::MUL
+        } else {
+            Token::GARBAGE
+        }
     }
 
     fn get_do_dont(&mut self) -> Token {
-        let dont_buf = &self.text[self.pos..self.pos + 5];
-        match dont_buf {
-            ['d', 'o', 'n', '\'', 't'] => {
-                self.pos += 4;
-                Token::DONT
-            }
-            _ => {
-                let do_buf = &dont_buf[0..2];
-                match do_buf {
-                    ['d', 'o'] => {
-                        self.pos += 1;
-                        Token::DO
-                    }
-                    _ => Token::GARBAGE,
-                }
-            }
+        let rest = &self.text[self.pos..];
+        if rest.starts_with(&['d', 'o', 'n', '\'', 't']) {
+            self.pos += 4;
+            Token::DONT
+        } else if rest.starts_with(&['d', 'o']) {
+            self.pos += 1;
+            Token::DO
+        } else {
+            Token::GARBAGE
         }
     }
 
     fn get_num(&mut self) -> Token {
         let start = self.pos;
-        while self.text[self.pos + 1].is_ascii_digit() {
+        while self
+            .text
+            .get(self.pos + 1)
+            .is_some_and(|c| c.is_ascii_digit())
+        {
             self.pos += 1;
-            if self.pos >= self.text.len() - 1 {
-                return Token::EOF;
-            }
         }
 
         let num = self.text[start..=self.pos]
             .iter()
             .cloned()
             .collect::<String>();
-        let parsed = num
-            .parse::<usize>()
-            .expect("Should have collected only digits");
 
-        Token::INT(parsed)
+        // Too many digits to fit in a usize can't be a valid operand.
+        num.parse().map_or(Token::GARBAGE, Token::INT)
     }
 
     fn next_token(&mut self) -> Token {
@@ -132,7 +125,7 @@ struct Parser {
 }
 
 impl Parser {
-    fn new(input: &String, state_sensitive: bool) -> Self {
+    fn new(input: &str, state_sensitive: bool) -> Self {
         let mut lex = Lexer::new(input);
         let cur_token = lex.next_token();
         let peek_token = lex.next_token();
@@ -221,7 +214,7 @@ impl Parser {
     }
 
     fn eval(self) -> usize {
-        self.into_iter().fold(0, |acc, cur| acc + cur.eval())
+        self.map(|mul| mul.eval()).sum()
     }
 }
 
@@ -233,16 +226,29 @@ impl Iterator for Parser {
     }
 }
 
-pub fn day3(input: String) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
 
-fn part1(input: &String) -> usize {
+fn part1(input: &str) -> usize {
     Parser::new(input, false).eval()
 }
 
-fn part2(input: &String) -> usize {
+fn part2(input: &str) -> usize {
     Parser::new(input, true).eval()
 }
 
@@ -251,8 +257,8 @@ mod day3_test {
     use super::{Mul, Parser};
 
     fn parse(input: &str) -> Vec<Mul> {
-        let parser = Parser::new(&input.into(), true);
-        parser.into_iter().collect()
+        let parser = Parser::new(input, true);
+        parser.collect()
     }
 
     #[test]
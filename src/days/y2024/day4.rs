@@ -0,0 +1,164 @@
+use itertools::Itertools;
+
+use crate::days::{
+    grid::{Grid, Point},
+    parse, Answer, ParseError, Solution,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        part2(grid).into()
+    }
+}
+
+/// Reads the letters, padding rows shorter than the longest with `.`, which is in no
+/// word.
+fn parse(input: &str) -> parse::Result<Grid<char>> {
+    let mut rows = input
+        .lines()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return Err(ParseError::eof(input, "expected a grid"));
+    }
+    for row in &mut rows {
+        row.resize(width, '.');
+    }
+
+    Grid::from_rows(input, rows)
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    let at = |p: Point| grid.get(p).copied().unwrap_or('.');
+
+    let mut count = 0;
+    for (p, &ch) in grid.iter() {
+        if ch != 'A' {
+            continue;
+        }
+
+        let right_diag = [at(p + Point::new(-1, -1)), ch, at(p + Point::new(1, 1))];
+        let right_diag: String = right_diag.iter().collect();
+
+        let left_diag = [at(p + Point::new(-1, 1)), ch, at(p + Point::new(1, -1))];
+        let left_diag: String = left_diag.iter().collect();
+
+        if (right_diag == "SAM" || right_diag == "MAS")
+            && (left_diag == "SAM" || left_diag == "MAS")
+        {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    let mut count = 0;
+    for (p, &ch) in grid.iter() {
+        if ch != 'X' {
+            continue;
+        }
+
+        for next in grid.neighbors8(p) {
+            let offset = next - p;
+            if "MAS"
+                .chars()
+                .zip(1..)
+                .all(|(c, k)| grid.get(p + offset * k) == Some(&c))
+            {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod day4_test {
+    use super::{parse, part1 as p1, part2 as p2};
+
+    fn part1(input: &str) -> usize {
+        p1(&parse(input).unwrap())
+    }
+    fn part2(input: &str) -> usize {
+        p2(&parse(input).unwrap())
+    }
+
+    #[test]
+    fn test_horizontal() {
+        assert_eq!(3, part1("XMASXMASXXXSAMX"));
+        assert_eq!(3, part1("SAMXXMAXMASAMX"));
+        assert_eq!(2, part1("XAMXAMXAMSAMXSAMX"));
+        assert_eq!(0, part1("XXXXXXXXXXXXXXXXXXAMX"));
+        assert_eq!(2, part1("XMASAMX"));
+        assert_eq!(1, part1("XXMAS"));
+        assert_eq!(1, part1("SSAMX"));
+    }
+
+    #[test]
+    fn test_vertical() {
+        assert_eq!(1, part1("X\nM\nA\nS\n"));
+        assert_eq!(2, part1("XX\nMM\nAA\nSS\n"));
+        assert_eq!(1, part1("S\nA\nM\nX\n"));
+        assert_eq!(2, part1("SS\nAA\nMM\nXX\n"));
+        assert_eq!(2, part1("XS\nMA\nAM\nSX\n"));
+        assert_eq!(1, part1("XA\nMM\nAX\nSX\n"));
+        assert_eq!(0, part1("X\nM\nAS\n"));
+        assert_eq!(1, part1("XMAS\n"));
+    }
+
+    #[test]
+    fn test_ragged() {
+        assert_eq!(2, part1("X\nM\nA\nSAMX"));
+        assert_eq!(1, part1("...S\n..A\n.M\nX"));
+        assert_eq!(
+            parse("").unwrap_err().to_string(),
+            "line 1, column 1: expected a grid"
+        );
+        assert_eq!(
+            parse("\n\n").unwrap_err().to_string(),
+            "line 3, column 1: expected a grid"
+        );
+    }
+
+    #[test]
+    fn test_diagonal() {
+        assert_eq!(1, part1("...X\n..M.\n.A..\nS..."));
+        assert_eq!(1, part1("...S\n..A.\n.M..\nX..."));
+        assert_eq!(2, part1("...SX\n..AM.\n.MA..\nXS..."));
+        assert_eq!(1, part1("...X\n..M.\n.A..\nS..."));
+        assert_eq!(1, part1("...S\n..A.\n.M..\nX..."));
+        assert_eq!(2, part1("...SX\n..AM.\n.MA..\nXS..."));
+        assert_eq!(1, part1("X....\n.M...\n..A..\n...S.\n"));
+        assert_eq!(4, part1("XS......SX\n.MA....AM.\n..AM..MA..\n...SXXS..."));
+    }
+
+    #[test]
+    fn test_example() {
+        let input = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+        assert_eq!(18, part1(input));
+    }
+
+    #[test]
+    fn test_xmas() {
+        assert_eq!(1, part2("M.S\n.A.\nM.S"));
+        assert_eq!(1, part2("S.M\n.A.\nS.M"));
+        assert_eq!(1, part2("M.M\n.A.\nS.S"));
+        assert_eq!(1, part2("S.S\n.A.\nM.M"));
+    }
+}
@@ -1,8 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::days::{parse, Answer, Solution};
+
 type Rules = HashMap<usize, Vec<usize>>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Update(Vec<usize>);
 
 impl Update {
@@ -70,35 +72,40 @@ impl Update {
 }
 
 #[derive(Debug)]
-struct Rulebook {
+pub struct Rulebook {
     rules: Rules,
     updates: Vec<Update>,
 }
 
 impl Rulebook {
-    fn from_string(input: &str) -> Self {
-        let mut parts = input.split("\n\n");
-        let rules = parts.next().unwrap();
-        let updates = parts.next().unwrap();
+    fn from_string(input: &str) -> parse::Result<Self> {
+        let [rules, updates] = parse::sections(input)?;
 
-        let rules = rules.lines().fold(Rules::new(), |mut acc, cur| {
-            let mut parts = cur.split("|");
-            let before: usize = parts.next().unwrap().parse().unwrap();
-            let after: usize = parts.next().unwrap().parse().unwrap();
+        let mut acc = Rules::new();
+        for line in rules.lines() {
+            let (before, after) = parse::split_once(input, line, "|")?;
+            let before: usize = parse::number(input, before)?;
+            let after: usize = parse::number(input, after)?;
 
             acc.entry(before)
                 .and_modify(|v| v.push(after))
                 .or_insert(vec![after]);
-
-            acc
-        });
+        }
 
         let updates = updates
             .lines()
-            .map(|line| Update(line.split(',').map(|e| e.parse().unwrap()).collect()))
-            .collect();
+            .map(|line| {
+                line.split(',')
+                    .map(|e| parse::number(input, e))
+                    .collect::<parse::Result<_>>()
+                    .map(Update)
+            })
+            .collect::<parse::Result<_>>()?;
 
-        Self { rules, updates }
+        Ok(Self {
+            rules: acc,
+            updates,
+        })
     }
 
     fn part1(&self) -> usize {
@@ -108,11 +115,12 @@ impl Rulebook {
             .fold(0, |acc, cur| acc + cur.middle())
     }
 
-    fn part2(&mut self) -> usize {
+    fn part2(&self) -> usize {
         self.updates
-            .iter_mut()
+            .iter()
             .filter(|u| !u.is_valid(&self.rules))
             .map(|u| {
+                let mut u = u.clone();
                 u.fix(&self.rules);
                 u
             })
@@ -120,17 +128,32 @@ impl Rulebook {
     }
 }
 
-pub fn day5(input: String) {
-    let mut a = Rulebook::from_string(&input);
-    println!("Part 1: {:?}", a.part1());
-    println!("Part 2: {:?}", a.part2());
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Rulebook;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Rulebook::from_string(input)
+    }
+
+    fn part1(&self, rulebook: &Self::Input) -> Answer {
+        rulebook.part1().into()
+    }
+
+    fn part2(&self, rulebook: &Self::Input) -> Answer {
+        rulebook.part2().into()
+    }
 }
 
 #[cfg(test)]
 mod day5_test {
-    use super::Rulebook;
+    use std::cmp::Ordering;
+
+    use super::{Rulebook, Rules, Update};
+    use crate::days::prop::{self, Rng};
 
-    const INPUT: &'static str = "47|53
+    const INPUT: &str = "47|53
 97|13
 97|61
 97|47
@@ -160,7 +183,7 @@ mod day5_test {
 97,13,75,29,47";
     #[test]
     fn test_swaps() {
-        let mut rulebook = Rulebook::from_string(INPUT);
+        let mut rulebook = Rulebook::from_string(INPUT).unwrap();
         let mut a = rulebook
             .updates
             .iter_mut()
@@ -183,4 +206,40 @@ mod day5_test {
 
         assert_eq!(0, a.count());
     }
+
+    /// Rules ordering every pair of a few pages, as the puzzle's do, and an update
+    /// of some of those pages in any order.
+    fn rules_and_update(rng: &mut Rng) -> (Rules, Update) {
+        let mut pages = (10..100).collect::<Vec<_>>();
+        rng.shuffle(&mut pages);
+        pages.truncate(rng.range(1..=12));
+
+        let mut rules = Rules::new();
+        for (i, &before) in pages.iter().enumerate() {
+            rules.insert(before, pages[i + 1..].to_vec());
+        }
+
+        let mut update = pages;
+        update.truncate(rng.range(1..=update.len()));
+        rng.shuffle(&mut update);
+        (rules, Update(update))
+    }
+
+    #[test]
+    fn test_fix_is_valid() {
+        prop::check(rules_and_update, |(rules, update)| {
+            let mut fixed = update.clone();
+            fixed.fix(rules);
+            assert!(fixed.is_valid(rules));
+
+            // Sorting by the rules directly is the obvious, slower way to fix it.
+            let mut sorted = update.0.clone();
+            sorted.sort_by(|a, b| match rules.get(a) {
+                Some(after) if after.contains(b) => Ordering::Less,
+                _ if a == b => Ordering::Equal,
+                _ => Ordering::Greater,
+            });
+            assert_eq!(fixed.0, sorted);
+        });
+    }
 }
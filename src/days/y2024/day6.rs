@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use crate::days::{
+    grid::{Direction, Grid, Point},
+    parallel, parse, Answer, Solution,
+};
+
+#[derive(Clone)]
+struct Guard {
+    pos: Point,
+    direction: Direction,
+    seen: HashSet<Point>,
+    new_obstacle: Option<Point>,
+}
+
+impl Guard {
+    fn new(pos: Point) -> Self {
+        let mut guard = Guard {
+            pos,
+            direction: Direction::Up,
+            seen: HashSet::new(),
+            new_obstacle: None,
+        };
+        guard.seen.insert(guard.pos);
+        guard
+    }
+
+    fn new_pos(&self) -> Point {
+        self.pos.step(self.direction)
+    }
+
+    fn check_bounds(&self, map: &Map) -> bool {
+        map.contains(self.new_pos())
+    }
+
+    fn is_blocked(&self, map: &Map) -> bool {
+        let new_pos = self.new_pos();
+        if !map.contains(new_pos) {
+            return true;
+        }
+
+        map[new_pos] == '#' || self.new_obstacle == Some(new_pos)
+    }
+
+    fn walk(&mut self, map: &Map) {
+        if self.is_blocked(map) {
+            self.direction = self.direction.turn_right();
+            return;
+        }
+        self.pos = self.new_pos();
+    }
+
+    fn track_walk(&mut self, map: &Map) {
+        self.walk(map);
+        self.seen.insert(self.pos);
+    }
+}
+
+type Map = Grid<char>;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        let map = Grid::parse(input, |c| matches!(c, '.' | '#' | '^').then_some(c))?;
+        map.find_one(input, "guard", |&c| c == '^')?;
+        Ok(map)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Answer {
+        part1(map).seen.len().into()
+    }
+
+    fn part2(&self, map: &Self::Input) -> Answer {
+        let part1_result = part1(map);
+        part2(map, &part1_result.seen).into()
+    }
+}
+
+fn part1(map: &Map) -> Guard {
+    let start = map.find(&'^').expect("Expected guard to be in the map");
+
+    let mut guard = Guard::new(start);
+    while guard.check_bounds(map) {
+        guard.track_walk(map);
+    }
+
+    guard
+}
+
+fn check_cycle(map: &Map, mut tortoise: Guard) -> bool {
+    let mut hare = tortoise.clone();
+
+    while hare.check_bounds(map) {
+        hare.walk(map);
+        if !hare.check_bounds(map) {
+            break;
+        }
+        hare.walk(map);
+        tortoise.walk(map);
+        if tortoise.pos == hare.pos && tortoise.direction == hare.direction {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn part2(map: &Map, part1_seen: &HashSet<Point>) -> usize {
+    let start = map.find(&'^').expect("Expected guard to be in the map");
+    let possibilities: Vec<Point> = part1_seen.iter().copied().collect();
+
+    parallel::map(&possibilities, |&obstacle| {
+        let mut tortoise = Guard::new(start);
+        tortoise.new_obstacle = Some(obstacle);
+        check_cycle(map, tortoise)
+    })
+    .into_iter()
+    .filter(|&cycle| cycle)
+    .count()
+}
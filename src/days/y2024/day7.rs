@@ -1,54 +1,59 @@
-use std::time::Instant;
+use crate::days::{parallel, parse, Answer, Solution};
 
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     target: usize,
     components: Vec<usize>,
 }
 
 impl Equation {
-    fn from_line(line: &str) -> Self {
-        let mut parts = line.split(": ");
+    fn from_line(input: &str, line: &str) -> parse::Result<Self> {
+        let (target, components) = parse::split_once(input, line, ": ")?;
 
-        let target: usize = parts.next().unwrap().parse().unwrap();
-        let components: Vec<usize> = parts
-            .next()
-            .unwrap()
+        let target = parse::number(input, target)?;
+        let components = components
             .split(' ')
-            .map(|n| n.parse().unwrap())
-            .collect();
+            .map(|n| parse::number(input, n))
+            .collect::<parse::Result<_>>()?;
 
-        Self { target, components }
+        Ok(Self { target, components })
     }
 }
 
-pub fn day7(input: String) {
-    let parse_start = Instant::now();
-    let eqs: Vec<_> = input.lines().map(Equation::from_line).collect();
-    println!("Parsing took {:?}", parse_start.elapsed());
-
-    let start = Instant::now();
-    let part1 = eqs
-        .iter()
-        .filter(|e| is_solvable_part1(e))
-        .fold(0, |acc, cur| acc + cur.target);
-
-    println!("Part 1: {part1} in {:?}", start.elapsed());
-    let start = Instant::now();
-    let part2 = eqs
-        .iter()
-        .filter(|e| is_solvable_part2(e))
-        .fold(0, |acc, cur| acc + cur.target);
-    println!("Part 2: {part2} in {:?}", start.elapsed());
-    println!("Overall: {:?}", parse_start.elapsed());
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| Equation::from_line(input, line))
+            .collect()
+    }
+
+    fn part1(&self, eqs: &Self::Input) -> Answer {
+        total_solvable(eqs, is_solvable_part1).into()
+    }
+
+    fn part2(&self, eqs: &Self::Input) -> Answer {
+        total_solvable(eqs, is_solvable_part2).into()
+    }
+}
+
+/// The sum of the targets of the equations `solvable` accepts.
+fn total_solvable(eqs: &[Equation], solvable: fn(&Equation) -> bool) -> usize {
+    parallel::map(eqs, |e| if solvable(e) { e.target } else { 0 })
+        .into_iter()
+        .sum()
 }
 
 fn is_solvable_part1(eq: &Equation) -> bool {
-    if eq.target == 0 && eq.components.len() == 0 {
+    if eq.target == 0 && eq.components.is_empty() {
         return true;
     }
 
-    if eq.components.len() == 0 {
+    if eq.components.is_empty() {
         return false;
     }
 
@@ -59,7 +64,7 @@ fn is_solvable_part1(eq: &Equation) -> bool {
         return true;
     }
 
-    if target % component == 0 {
+    if target.is_multiple_of(component) {
         let remaining = target / component;
         if remaining == 1 {
             return true;
@@ -84,11 +89,11 @@ fn is_solvable_part1(eq: &Equation) -> bool {
 }
 
 fn is_solvable_part2(eq: &Equation) -> bool {
-    if eq.target == 0 && eq.components.len() == 0 {
+    if eq.target == 0 && eq.components.is_empty() {
         return true;
     }
 
-    if eq.components.len() == 0 {
+    if eq.components.is_empty() {
         return false;
     }
 
@@ -116,7 +121,7 @@ fn is_solvable_part2(eq: &Equation) -> bool {
         };
     }
 
-    if target % component == 0 {
+    if target.is_multiple_of(component) {
         let remaining = target / component;
         if remaining == 1 {
             return true;
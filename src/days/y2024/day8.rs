@@ -0,0 +1,116 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::days::{
+    grid::{Grid, Point},
+    parse, Answer, Solution,
+};
+
+type Location = Point;
+#[derive(Debug)]
+pub struct Map {
+    grid: Grid<char>,
+    frequencies_locations: HashMap<char, Vec<Location>>,
+}
+
+impl Map {
+    fn new(input: &str) -> parse::Result<Self> {
+        let grid = Grid::parse(input, Some)?;
+
+        let mut frequencies_locations: HashMap<char, Vec<Location>> = HashMap::new();
+        for (p, &c) in grid.iter() {
+            if c != '.' {
+                frequencies_locations
+                    .entry(c)
+                    .and_modify(|v| v.push(p))
+                    .or_insert(vec![p]);
+            }
+        }
+
+        Ok(Self {
+            grid,
+            frequencies_locations,
+        })
+    }
+
+    fn add_antinode(
+        &self,
+        antinodes: &mut HashSet<Location>,
+        a: &Location,
+        b: &Location,
+        loc: Location,
+    ) {
+        if self.grid.contains(loc) && &loc != a && &loc != b {
+            antinodes.insert(loc);
+        }
+    }
+
+    fn part_one(&self) -> usize {
+        let mut antinodes: HashSet<Location> = HashSet::new();
+        for locs in self.frequencies_locations.values() {
+            for c in locs.iter().combinations(2) {
+                let (&p1, &p2) = (c[0], c[1]);
+
+                let diff = p1 - p2;
+                let a1 = p1 + diff;
+                let a2 = p1 - diff;
+                let b1 = p2 + diff;
+                let b2 = p2 - diff;
+
+                self.add_antinode(&mut antinodes, c[0], c[1], a1);
+                self.add_antinode(&mut antinodes, c[0], c[1], a2);
+                self.add_antinode(&mut antinodes, c[0], c[1], b1);
+                self.add_antinode(&mut antinodes, c[0], c[1], b2);
+            }
+        }
+
+        antinodes.len()
+    }
+
+    fn add_antinodes_in_line(
+        &self,
+        start: Location,
+        diff: Location,
+        antinodes: &mut HashSet<Location>,
+    ) {
+        let mut cur: Location = start + diff;
+        while self.grid.contains(cur) {
+            antinodes.insert(cur);
+            cur = cur + diff;
+        }
+    }
+
+    fn part_two(&self) -> usize {
+        let mut antinodes: HashSet<Location> = HashSet::new();
+        for locs in self.frequencies_locations.values() {
+            for c in locs.iter().combinations(2) {
+                let (&p1, &p2) = (c[0], c[1]);
+                let diff = p1 - p2;
+                self.add_antinodes_in_line(p1, diff, &mut antinodes);
+                self.add_antinodes_in_line(p1, diff * -1, &mut antinodes);
+                self.add_antinodes_in_line(p2, diff, &mut antinodes);
+                self.add_antinodes_in_line(p2, diff * -1, &mut antinodes);
+            }
+        }
+
+        antinodes.len()
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Map::new(input)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Answer {
+        map.part_one().into()
+    }
+
+    fn part2(&self, map: &Self::Input) -> Answer {
+        map.part_two().into()
+    }
+}
@@ -0,0 +1,299 @@
+use crate::days::{parse, Answer, ParseError, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        let map = input.trim_end();
+        if map.is_empty() {
+            return Err(ParseError::eof(input, "expected a disk map"));
+        }
+
+        map.char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                    ParseError::at(input, &map[i..], format!("expected a digit, found `{c}`"))
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(blocks(input)).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// The disk as one entry per block: the ID of the file it belongs to, or -1 if free.
+fn blocks(input: &[usize]) -> Vec<isize> {
+    let mut disk: Vec<isize> = Vec::new();
+    let mut id = 0;
+    input.iter().enumerate().for_each(|(idx, &space)| {
+        let is_free = idx & 1 == 1;
+
+        let new_elems = if is_free {
+            vec![-1; space]
+        } else {
+            let elems = vec![id; space];
+            id += 1;
+            elems
+        };
+
+        disk.extend_from_slice(&new_elems);
+    });
+
+    disk
+}
+
+fn part1(mut disk: Vec<isize>) -> usize {
+    compact_blocks(&mut disk);
+
+    disk.iter()
+        .enumerate()
+        .filter(|&(_, &id)| id >= 0)
+        .map(|(i, &id)| i * id as usize)
+        .sum()
+}
+
+/// Moves blocks one at a time from the end of the disk to the leftmost free block.
+fn compact_blocks(disk: &mut [isize]) {
+    let mut end = disk.len() - 1;
+    let mut start = 0;
+
+    while end > start {
+        let val = match disk[end] {
+            -1 => {
+                end -= 1;
+                continue;
+            }
+            id => id,
+        };
+
+        match disk[start] {
+            -1 => {
+                disk[start] = val;
+                disk[end] = -1;
+                start += 1;
+                end -= 1;
+            }
+            _ => {
+                start += 1;
+                continue;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Content {
+    // length
+    Free(usize),
+    // ID, length
+    Occupied(usize, usize),
+}
+
+fn part2(input: &[usize]) -> usize {
+    let mut idx = 0;
+    let mut sum = 0;
+    for e in compact_files(input) {
+        match e {
+            Content::Free(space) => {
+                idx += space;
+            }
+            Content::Occupied(id, space) => {
+                sum += id * (idx..idx + space).sum::<usize>();
+                idx += space;
+            }
+        }
+    }
+
+    sum
+}
+
+/// Moves whole files, highest ID first, to the leftmost free span they fit in.
+fn compact_files(input: &[usize]) -> Vec<Content> {
+    let mut disk: Vec<Content> = Vec::new();
+    let mut id = 0;
+    for (i, &space) in input.iter().enumerate() {
+        let is_free = i & 1 == 1;
+
+        if is_free {
+            disk.push(Content::Free(space));
+        } else {
+            disk.push(Content::Occupied(id, space));
+            id += 1;
+        }
+    }
+
+    let mut i = 0;
+    while i < disk.len() {
+        let available_space = match disk[i] {
+            Content::Free(space) => space,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+
+        for j in (i..disk.len()).rev() {
+            if j < i {
+                unreachable!();
+            }
+            match disk[j] {
+                Content::Occupied(id, space) if space <= available_space => {
+                    disk[i] = Content::Occupied(id, space);
+                    disk[j] = Content::Free(space);
+
+                    if available_space > space {
+                        let a = &[Content::Free(available_space - space)];
+                        disk.splice(i + 1..i + 1, a.iter().cloned());
+                    } else {
+                        i += 1;
+                    }
+
+                    break;
+                }
+                _ => continue,
+            }
+        }
+
+        i += 1;
+    }
+
+    disk
+}
+
+#[cfg(test)]
+mod day9_test {
+    use std::collections::HashMap;
+
+    use super::{blocks, compact_blocks, compact_files, part1, part2, Content};
+    use crate::days::prop::{self, Rng};
+
+    /// A disk map of files of 1 to 9 blocks, with 0 to 9 free blocks between them.
+    fn disk_map(rng: &mut Rng) -> Vec<usize> {
+        let files = rng.range(1..=10);
+        (0..files * 2 - 1)
+            .map(|i| {
+                if i % 2 == 0 {
+                    rng.range(1..=9)
+                } else {
+                    rng.range(0..=9)
+                }
+            })
+            .collect()
+    }
+
+    fn checksum(disk: &[isize]) -> usize {
+        disk.iter()
+            .enumerate()
+            .filter(|&(_, &id)| id >= 0)
+            .map(|(i, &id)| i * id as usize)
+            .sum()
+    }
+
+    fn counts(disk: &[isize]) -> HashMap<isize, usize> {
+        disk.iter().fold(HashMap::new(), |mut counts, &id| {
+            *counts.entry(id).or_default() += 1;
+            counts
+        })
+    }
+
+    /// Where each file starts and how long it is, by ID.
+    fn files(disk: &[Content]) -> HashMap<usize, (usize, usize)> {
+        let mut files = HashMap::new();
+        let mut idx = 0;
+        for content in disk {
+            match *content {
+                Content::Free(len) => idx += len,
+                Content::Occupied(id, len) => {
+                    assert!(files.insert(id, (idx, len)).is_none(), "file {id} split");
+                    idx += len;
+                }
+            }
+        }
+        files
+    }
+
+    #[test]
+    fn test_compact_blocks() {
+        prop::check(disk_map, |map| {
+            let before = blocks(map);
+            let mut after = before.clone();
+            compact_blocks(&mut after);
+
+            assert_eq!(counts(&after), counts(&before));
+            let files = after.iter().take_while(|&&id| id >= 0).count();
+            assert!(after[files..].iter().all(|&id| id < 0), "{after:?}");
+        });
+    }
+
+    #[test]
+    fn test_compact_files() {
+        prop::check(disk_map, |map| {
+            let unmoved = map
+                .iter()
+                .enumerate()
+                .map(|(i, &len)| match i % 2 {
+                    0 => Content::Occupied(i / 2, len),
+                    _ => Content::Free(len),
+                })
+                .collect::<Vec<_>>();
+            let before = files(&unmoved);
+            let after = files(&compact_files(map));
+
+            assert_eq!(after.len(), before.len());
+            for (id, &(start, len)) in &after {
+                let (old_start, old_len) = before[id];
+                assert_eq!(len, old_len);
+                assert!(start <= old_start, "file {id} moved right");
+            }
+        });
+    }
+
+    /// Moves the last file block to the first free one until the free space is all at
+    /// the end, one block at a time.
+    fn naive_part1(map: &[usize]) -> usize {
+        let mut disk = blocks(map);
+        while let (Some(free), Some(last)) = (
+            disk.iter().position(|&id| id < 0),
+            disk.iter().rposition(|&id| id >= 0),
+        ) {
+            if free > last {
+                break;
+            }
+            disk.swap(free, last);
+        }
+        checksum(&disk)
+    }
+
+    /// Moves each file, highest ID first, to the first run of free blocks to its left
+    /// that is long enough, scanning the blocks for both.
+    fn naive_part2(map: &[usize]) -> usize {
+        let mut disk = blocks(map);
+        let last_id = disk.iter().copied().max().unwrap();
+        for id in (0..=last_id).rev() {
+            let start = disk.iter().position(|&b| b == id).unwrap();
+            let len = disk.iter().filter(|&&b| b == id).count();
+            if let Some(to) = (0..start).find(|&to| disk[to..to + len].iter().all(|&b| b < 0)) {
+                for i in 0..len {
+                    disk.swap(to + i, start + i);
+                }
+            }
+        }
+        checksum(&disk)
+    }
+
+    #[test]
+    fn test_against_naive() {
+        prop::check(disk_map, |map| {
+            assert_eq!(part1(blocks(map)), naive_part1(map));
+            assert_eq!(part2(map), naive_part2(map));
+        });
+    }
+}
@@ -0,0 +1,29 @@
+use std::{fs, path::PathBuf};
+
+use crate::{answers::Expected, days, days::Part};
+
+const DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+/// Solves `part` of the example `examples/<year>/<name>.txt` and compares the answer
+/// against the one in `<name>.toml`.
+fn check(year: u32, day: usize, name: &str, part: u8) {
+    let dir = PathBuf::from(DIR).join(year.to_string());
+    let read = |ext| {
+        let path = dir.join(format!("{name}.{ext}"));
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+    };
+    let expected = Expected::parse(&read("toml")).unwrap();
+    let part = Part::parse(&part.to_string()).unwrap();
+
+    let solution = days::day(year, day)
+        .unwrap_or_else(|| panic!("{name}: day {day} of {year} is not registered"));
+    let parsed = solution.parse(&read("txt")).unwrap();
+    assert_eq!(
+        parsed.solve(part).to_string(),
+        expected.get(part).unwrap(),
+        "{name}, part {}",
+        part.number()
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
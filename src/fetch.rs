@@ -0,0 +1,232 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{config::Config, http, inputs::Inputs};
+
+/// A file the fetcher is responsible for, and whether it was downloaded just now or
+/// was already there.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub cached: bool,
+}
+
+/// Downloads puzzle inputs and the examples on puzzle pages into the inputs
+/// directory. Nothing already there is downloaded again, and requests are spaced at
+/// least the configured rate limit apart.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    rate_limit: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        Ok(Self {
+            base_url: config.base_url.clone(),
+            session: config.session()?.to_string(),
+            rate_limit: config.rate_limit,
+            last_request: None,
+        })
+    }
+
+    fn get(&mut self, path: &str) -> Result<String, String> {
+        if let Some(last) = self.last_request {
+            thread::sleep(self.rate_limit.saturating_sub(last.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+
+        let url = format!("{}{path}", self.base_url);
+        let cookie = format!("session={}", self.session);
+        let response = http::get(
+            &url,
+            &[("Cookie", &cookie), ("User-Agent", http::USER_AGENT)],
+        )?;
+
+        match response.status {
+            200 => Ok(response.body),
+            400 | 401 => Err(format!(
+                "{url}: HTTP {}, is the session token still valid?",
+                response.status
+            )),
+            404 => Err(format!("{url}: HTTP 404, is the puzzle unlocked yet?")),
+            status => match response
+                .body
+                .lines()
+                .next()
+                .filter(|l| !l.trim().is_empty())
+            {
+                Some(line) => Err(format!("{url}: HTTP {status}, {}", line.trim())),
+                None => Err(format!("{url}: HTTP {status}")),
+            },
+        }
+    }
+
+    /// Fetches the input of `day` and the examples on its puzzle page, unless they are
+    /// already in `inputs`.
+    pub fn fetch(
+        &mut self,
+        inputs: &Inputs,
+        year: u32,
+        day: usize,
+    ) -> Result<Vec<Fetched>, String> {
+        let mut fetched = vec![];
+
+        match cached(inputs.find(day, false)) {
+            Some(path) => fetched.push(Fetched { path, cached: true }),
+            None => {
+                let input = self.get(&format!("/{year}/day/{day}/input"))?;
+                let path = inputs.year_path(&Inputs::file_name(day, false));
+                save(&path, &input)?;
+                fetched.push(Fetched {
+                    path,
+                    cached: false,
+                });
+            }
+        }
+
+        match cached(inputs.find(day, true)) {
+            Some(path) => fetched.push(Fetched { path, cached: true }),
+            None => {
+                let page = self.get(&format!("/{year}/day/{day}"))?;
+                for (i, example) in examples(&page).iter().enumerate() {
+                    let path = inputs.year_path(&Inputs::example_name(day, i + 1));
+                    save(&path, example)?;
+                    fetched.push(Fetched {
+                        path,
+                        cached: false,
+                    });
+                }
+            }
+        }
+
+        Ok(fetched)
+    }
+}
+
+/// A file found in the inputs, unless it is an empty placeholder waiting to be fetched.
+fn cached(path: Option<PathBuf>) -> Option<PathBuf> {
+    path.filter(|path| fs::metadata(path).is_ok_and(|m| m.len() > 0))
+}
+
+fn save(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// The text of every `<pre><code>` block on a puzzle page, in order, which is where
+/// the examples are.
+pub fn examples(page: &str) -> Vec<String> {
+    const OPEN: &str = "<pre><code>";
+    const CLOSE: &str = "</code></pre>";
+
+    let mut examples = vec![];
+    let mut rest = page;
+    while let Some(start) = rest.find(OPEN) {
+        rest = &rest[start + OPEN.len()..];
+        let Some(end) = rest.find(CLOSE) else {
+            break;
+        };
+
+        let mut example = http::html_text(&rest[..end]);
+        if !example.ends_with('\n') {
+            example.push('\n');
+        }
+        examples.push(example);
+        rest = &rest[end + CLOSE.len()..];
+    }
+
+    examples
+}
+
+#[cfg(test)]
+mod fetch_test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use super::{examples, Fetcher};
+    use crate::{
+        config::Config,
+        http::stub::{self, Request},
+        inputs::Inputs,
+    };
+
+    const PAGE: &str =
+        "<article><p>For example:</p>\n<pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre>\
+        <p>Or:</p><pre><code>x</code></pre></article>";
+
+    /// Serves the input and the page above for day 3 of 2024 to the `token` session,
+    /// anything else is a 404.
+    fn serve() -> (String, Arc<Mutex<Vec<Request>>>) {
+        stub::serve(|request| {
+            if !request.has_header("Cookie: session=token") {
+                return ("400 Bad Request", "Please log in.".to_string());
+            }
+            match request.line.as_str() {
+                "GET /2024/day/3/input HTTP/1.1" => ("200 OK", "mul(2,4)\n".to_string()),
+                "GET /2024/day/3 HTTP/1.1" => ("200 OK", PAGE.to_string()),
+                _ => ("404 Not Found", "Not found".to_string()),
+            }
+        })
+    }
+
+    fn config(base_url: &str, session: &str, rate_limit: Duration) -> Config {
+        Config {
+            session: Some(session.to_string()),
+            base_url: base_url.to_string(),
+            rate_limit,
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(examples(PAGE), vec!["1 < 2\n3 & 4\n", "x\n"]);
+        assert!(examples("<p>No examples</p>").is_empty());
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base_url, seen) = serve();
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+        let inputs = Inputs::new(&dir, 2024);
+
+        let rate_limit = Duration::from_millis(100);
+        let mut fetcher = Fetcher::new(&config(&base_url, "token", rate_limit)).unwrap();
+        let start = Instant::now();
+        // An empty placeholder, as `new` leaves, is fetched anyway.
+        std::fs::create_dir_all(dir.join("2024")).unwrap();
+        std::fs::write(dir.join("2024/day03.txt"), "").unwrap();
+        let fetched = fetcher.fetch(&inputs, 2024, 3).unwrap();
+        assert!(start.elapsed() >= rate_limit);
+        assert_eq!(fetched.len(), 3);
+        assert!(fetched.iter().all(|f| !f.cached));
+        assert_eq!(inputs.read(3, false).unwrap(), "mul(2,4)\n");
+        assert_eq!(inputs.read(3, true).unwrap(), "1 < 2\n3 & 4\n");
+        assert!(dir.join("2024/day03.example2.txt").is_file());
+        assert_eq!(seen.lock().unwrap().len(), 2);
+
+        // Everything is cached now, so nothing is requested again.
+        let fetched = fetcher.fetch(&inputs, 2024, 3).unwrap();
+        assert_eq!(fetched.len(), 2);
+        assert!(fetched.iter().all(|f| f.cached));
+        assert_eq!(seen.lock().unwrap().len(), 2);
+
+        let err = fetcher.fetch(&inputs, 2024, 4).unwrap_err();
+        assert!(err.contains("404"));
+        let mut fetcher = Fetcher::new(&config(&base_url, "stale", Duration::ZERO)).unwrap();
+        let err = fetcher.fetch(&inputs, 2024, 5).unwrap_err();
+        assert!(err.contains("session token"));
+        assert!(inputs.find(5, false).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,375 @@
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+/// How the requests to the puzzle site identify this tool.
+pub const USER_AGENT: &str = concat!("advent-of-code/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends a request with `method` to `url`, with extra `headers` and an optional form
+/// encoded `body`. Plain `http://` URLs are handled here, `https://` ones by running
+/// `curl`, since the standard library has no TLS.
+pub fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        plain(method, rest, headers, body).map_err(|e| format!("{url}: {e}"))
+    } else if url.starts_with("https://") {
+        curl(method, url, headers, body).map_err(|e| format!("{url}: {e}"))
+    } else {
+        Err(format!("{url}: expected an http:// or https:// URL"))
+    }
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    request("GET", url, headers, None)
+}
+
+fn plain(
+    method: &str,
+    rest: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let address = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{host}:80")
+    };
+
+    let mut stream = TcpStream::connect(&address).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|()| stream.set_write_timeout(Some(TIMEOUT)))
+        .map_err(|e| e.to_string())?;
+
+    let mut request = format!("{method} {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = body {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let mut response = vec![];
+    stream
+        .read_to_end(&mut response)
+        .map_err(|e| e.to_string())?;
+    parse_response(&response)
+}
+
+fn parse_response(response: &[u8]) -> Result<Response, String> {
+    let split = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or("incomplete response")?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let mut body = response[split + 4..].to_vec();
+
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or("invalid status line")?;
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+    if chunked {
+        body = unchunk(&body).ok_or("invalid chunked body")?;
+    }
+
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn unchunk(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut out = vec![];
+    loop {
+        let line_end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&body[..line_end]).ok()?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Some(out);
+        }
+
+        out.extend(body.get(..size)?);
+        body = body.get(size + 2..)?;
+    }
+}
+
+/// Encodes `pairs` as an `application/x-www-form-urlencoded` body.
+pub fn form_encode(pairs: &[(&str, &str)]) -> String {
+    let encode = |s: &str| {
+        s.bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (b as char).to_string()
+                }
+                b' ' => "+".to_string(),
+                b => format!("%{b:02X}"),
+            })
+            .collect::<String>()
+    };
+
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// The text of a piece of HTML: tags such as `<em>` dropped and entities replaced.
+pub fn html_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The headers and body as a curl config file, so that they are passed on stdin and
+/// the session token never shows up in the process list.
+fn curl_config(headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let quoted = |s: &str| {
+        let escaped = s
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r");
+        format!("\"{escaped}\"")
+    };
+
+    let mut config = String::new();
+    for (name, value) in headers {
+        config.push_str(&format!(
+            "header = {}\n",
+            quoted(&format!("{name}: {value}"))
+        ));
+    }
+    if let Some(body) = body {
+        config.push_str(&format!("data = {}\n", quoted(body)));
+    }
+    config
+}
+
+/// Runs the request through the `curl` binary, so fetching and submitting over
+/// `https://` need curl on the `PATH`.
+fn curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let mut command = Command::new("curl");
+    command.args(["--silent", "--show-error", "--request", method]);
+    command.args(["--max-time", &TIMEOUT.as_secs().to_string()]);
+    command.args(["--write-out", "\n%{http_code}"]);
+    command.args(["--config", "-"]);
+    command.arg(url);
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not run curl: {e}"))?;
+    let config = curl_config(headers, body);
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(config.as_bytes())
+        .map_err(|e| format!("could not run curl: {e}"))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("could not run curl: {e}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').ok_or("no status from curl")?;
+    Ok(Response {
+        status: status.trim().parse().map_err(|_| "no status from curl")?,
+        body: body.to_string(),
+    })
+}
+
+/// A local HTTP server for tests to talk to instead of the real site.
+#[cfg(test)]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        /// The request line, such as `GET /2024/day/3 HTTP/1.1`.
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn has_header(&self, header: &str) -> bool {
+            self.headers.iter().any(|h| h == header)
+        }
+    }
+
+    /// Serves every request with the status line and body `respond` returns for it,
+    /// until the test ends. Returns the base URL and the requests seen so far.
+    pub fn serve(
+        respond: impl Fn(&Request) -> (&'static str, String) + Send + 'static,
+    ) -> (String, Arc<Mutex<Vec<Request>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(vec![]));
+
+        let log = Arc::clone(&seen);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut head = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_string();
+                    if line.is_empty() {
+                        break;
+                    }
+                    head.push(line);
+                }
+
+                let length = head
+                    .iter()
+                    .find_map(|h| h.strip_prefix("Content-Length: "))
+                    .map_or(0, |n| n.parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request = Request {
+                    line: head.remove(0),
+                    headers: head,
+                    body: String::from_utf8(body).unwrap(),
+                };
+                let (status, body) = respond(&request);
+                log.lock().unwrap().push(request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        (base_url, seen)
+    }
+}
+
+#[cfg(test)]
+mod http_test {
+    use super::{curl, curl_config, form_encode, html_text, parse_response, stub, Response};
+    use std::process::Command;
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(
+            form_encode(&[("level", "2"), ("answer", "4,6 3&")]),
+            "level=2&answer=4%2C6+3%26"
+        );
+        assert_eq!(html_text("<p>1 &lt; <em>2</em></p>"), "1 < 2");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let plain = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n1 2 3";
+        assert_eq!(
+            parse_response(plain),
+            Ok(Response {
+                status: 200,
+                body: "1 2 3".to_string()
+            })
+        );
+
+        let chunked =
+            b"HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n";
+        assert_eq!(parse_response(chunked).unwrap().body, "abcde");
+        assert_eq!(parse_response(chunked).unwrap().status, 404);
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+    }
+
+    #[test]
+    fn test_curl_config() {
+        assert_eq!(
+            curl_config(&[("Cookie", "session=a\"b\\c")], Some("x=1\ny")),
+            "header = \"Cookie: session=a\\\"b\\\\c\"\ndata = \"x=1\\ny\"\n"
+        );
+        assert_eq!(curl_config(&[], None), "");
+    }
+
+    #[test]
+    fn test_curl_sends_config() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            eprintln!("skipping test_curl_sends_config: curl is not on the PATH");
+            return;
+        }
+        let (base_url, seen) = stub::serve(|_| ("200 OK", "ok".to_string()));
+        let response = curl(
+            "POST",
+            &format!("{base_url}/submit"),
+            &[("Cookie", "session=secret")],
+            Some("level=1"),
+        )
+        .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "ok");
+
+        let seen = seen.lock().unwrap();
+        assert!(seen[0].has_header("Cookie: session=secret"));
+        assert_eq!(seen[0].body, "level=1");
+    }
+}
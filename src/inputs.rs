@@ -0,0 +1,165 @@
+use std::path::PathBuf;
+
+/// Environment variable overriding the inputs directory.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// The only event whose files may also sit directly in the directory, as they did
+/// before files were kept per year.
+pub const FLAT_YEAR: u32 = 2024;
+
+/// A directory of per-year files, such as the inputs or the answers: `<dir>/<year>/<name>`,
+/// falling back to `<dir>/<name>` for [`FLAT_YEAR`] only, so that no other year picks
+/// up its files.
+pub struct YearDir {
+    dir: PathBuf,
+    year: u32,
+}
+
+impl YearDir {
+    pub fn new(dir: impl Into<PathBuf>, year: u32) -> Self {
+        Self {
+            dir: dir.into(),
+            year,
+        }
+    }
+
+    /// Uses `dir` if given, then the `env` environment variable, then `default`.
+    pub fn from_env(dir: Option<&str>, env: &str, default: &str, year: u32) -> Self {
+        let dir = match dir {
+            Some(dir) => PathBuf::from(dir),
+            None => std::env::var_os(env)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(default)),
+        };
+
+        Self::new(dir, year)
+    }
+
+    /// Where a new file for this year is saved: `<dir>/<year>/<name>`.
+    pub fn year_path(&self, name: &str) -> PathBuf {
+        self.dir.join(self.year.to_string()).join(name)
+    }
+
+    pub fn candidates(&self, name: &str) -> Vec<PathBuf> {
+        let mut candidates = vec![self.year_path(name)];
+        if self.year == FLAT_YEAR {
+            candidates.push(self.dir.join(name));
+        }
+        candidates
+    }
+
+    pub fn find(&self, name: &str) -> Option<PathBuf> {
+        self.candidates(name)
+            .into_iter()
+            .find(|path| path.is_file())
+    }
+}
+
+/// Finds puzzle inputs by convention in a [`YearDir`]: `<dir>/<year>/dayNN.txt`.
+/// Example inputs use the `dayNN.example.txt` name instead.
+pub struct Inputs {
+    files: YearDir,
+}
+
+impl Inputs {
+    #[cfg(test)]
+    pub fn new(dir: impl Into<PathBuf>, year: u32) -> Self {
+        Self {
+            files: YearDir::new(dir, year),
+        }
+    }
+
+    /// Uses `dir` if given, then the `AOC_INPUTS` environment variable, then `inputs`.
+    pub fn from_env(dir: Option<&str>, year: u32) -> Self {
+        Self {
+            files: YearDir::from_env(dir, INPUTS_ENV, DEFAULT_DIR, year),
+        }
+    }
+
+    pub fn file_name(day: usize, example: bool) -> String {
+        if example {
+            format!("day{day:02}.example.txt")
+        } else {
+            format!("day{day:02}.txt")
+        }
+    }
+
+    /// The name of the `n`th example of a day, counting from 1: `dayNN.example.txt`,
+    /// then `dayNN.example2.txt` and so on.
+    pub fn example_name(day: usize, n: usize) -> String {
+        match n {
+            1 => Self::file_name(day, true),
+            n => format!("day{day:02}.example{n}.txt"),
+        }
+    }
+
+    /// Where a new file for this year is saved: `<dir>/<year>/<name>`.
+    pub fn year_path(&self, name: &str) -> PathBuf {
+        self.files.year_path(name)
+    }
+
+    pub fn candidates(&self, day: usize, example: bool) -> Vec<PathBuf> {
+        self.files.candidates(&Self::file_name(day, example))
+    }
+
+    pub fn find(&self, day: usize, example: bool) -> Option<PathBuf> {
+        self.files.find(&Self::file_name(day, example))
+    }
+
+    pub fn read(&self, day: usize, example: bool) -> Result<String, String> {
+        let path = self.find(day, example).ok_or_else(|| {
+            let tried = self
+                .candidates(day, example)
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" or ");
+            format!(
+                "no input for day {day}: expected {tried} (set {INPUTS_ENV} or pass --inputs to use another directory)"
+            )
+        })?;
+
+        std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod inputs_test {
+    use std::path::PathBuf;
+
+    use super::Inputs;
+
+    #[test]
+    fn test_candidates() {
+        let inputs = Inputs::new("in", 2024);
+        assert_eq!(
+            inputs.candidates(3, false),
+            vec![
+                PathBuf::from("in/2024/day03.txt"),
+                PathBuf::from("in/day03.txt")
+            ]
+        );
+        assert_eq!(
+            inputs.candidates(16, true),
+            vec![
+                PathBuf::from("in/2024/day16.example.txt"),
+                PathBuf::from("in/day16.example.txt")
+            ]
+        );
+
+        // Only the flat layout's own year falls back to it.
+        assert_eq!(
+            Inputs::new("in", 2023).candidates(3, false),
+            vec![PathBuf::from("in/2023/day03.txt")]
+        );
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let inputs = Inputs::new("does-not-exist", 2024);
+        let err = inputs.read(7, false).unwrap_err();
+        assert!(err.contains("does-not-exist/2024/day07.txt"));
+        assert!(err.contains("does-not-exist/day07.txt"));
+    }
+}
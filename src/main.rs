@@ -1,25 +1,515 @@
-use std::error::Error;
-mod days;
+use std::{
+    error::Error,
+    io::Read,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::Instant,
+};
+
+use answers::{Answers, Verdict};
+use baseline::Baseline;
+use cli::{Args, Command};
+use config::Config;
+use days::Answer;
+use fetch::Fetcher;
+use inputs::Inputs;
+use report::{Format, Record, Status};
+use submit::{Attempt, History, Outcome, Submitter};
 
+mod answers;
+mod baseline;
+mod bench;
+mod cli;
+mod config;
+mod days;
+#[cfg(test)]
+mod examples;
+mod fetch;
+mod http;
+mod inputs;
+mod report;
+mod scaffold;
+mod submit;
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() {
     let mut args = std::env::args();
     let program = args.next().unwrap();
-    if args.len() < 2 {
-        println!("Usage: {} <day> <input>", program);
-        return Ok(());
+    let usage = cli::USAGE.replace("{program}", &program);
+
+    let years = days::YEARS
+        .iter()
+        .map(|year| (year.year, year.days.len()))
+        .collect::<Vec<_>>();
+    let args = match Args::parse(args, &years) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{usage}");
+            std::process::exit(2);
+        }
+    };
+
+    days::visual::set_delay(args.delay);
+    if args.visualize || args.command == Command::Explore {
+        days::visual::enable();
+    }
+    if let Some(path) = &args.export {
+        if let Err(e) = days::visual::export(Path::new(path)) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    }
+
+    let result = match args.command {
+        Command::Run => run(&args),
+        Command::Bench => bench(&args),
+        Command::Check => check(&args),
+        Command::List => {
+            list(&args);
+            Ok(())
+        }
+        Command::Fetch => fetch(&args),
+        Command::Submit => submit(&args),
+        Command::New => new_day(&args),
+        Command::Explore => explore(&args),
+        Command::Help => {
+            println!("{usage}");
+            Ok(())
+        }
+    };
+
+    let result = result.and_then(|()| match days::visual::finish_export()? {
+        Some(exported) => {
+            eprintln!("{exported}");
+            Ok(())
+        }
+        None => Ok(()),
+    });
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+/// The solution to a day of the selected year, which parsing the arguments made sure
+/// is registered.
+fn day(args: &Args, day_num: usize) -> &'static dyn days::Day {
+    days::day(args.year, day_num).expect("selected days are registered")
+}
+
+fn read_input(args: &Args, day_num: usize) -> Result<String, Box<dyn Error>> {
+    match args.input.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}").into()),
+        None => {
+            Ok(Inputs::from_env(args.inputs_dir.as_deref(), args.year)
+                .read(day_num, args.example)?)
+        }
+    }
+}
+
+/// Runs `solve` for each selected day, on `--jobs` threads, yielding the results in
+/// day order. With a single job each day is only solved once the previous one is
+/// reported, so output isn't held back.
+fn for_each_day<R: Send>(
+    args: &Args,
+    solve: impl Fn(usize) -> R + Sync,
+    mut report: impl FnMut(usize, R) -> Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    if args.jobs > 1 {
+        let results = days::parallel::map_on(args.jobs, &args.days, |&day_num| solve(day_num));
+        for (&day_num, result) in args.days.iter().zip(results) {
+            report(day_num, result)?;
+        }
+    } else {
+        for &day_num in &args.days {
+            report(day_num, solve(day_num))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut records = vec![];
+    for_each_day(
+        args,
+        |day_num| run_day(args, day_num),
+        |day_num, result| {
+            let day_records = result?;
+            if args.format == Format::Text {
+                if args.days.len() > 1 {
+                    println!("Day {day_num}");
+                }
+                for record in &day_records {
+                    let answer = record.answer.as_ref().unwrap();
+                    println!("Part {}: {answer}", record.part.number());
+                }
+            }
+            records.extend(day_records);
+            Ok(())
+        },
+    )?;
+
+    report::print(args.format, &records);
+    Ok(())
+}
+
+fn run_day(args: &Args, day_num: usize) -> Result<Vec<Record>, String> {
+    let input = read_input(args, day_num).map_err(|e| e.to_string())?;
+    let parsed = day(args, day_num)
+        .parse(&input)
+        .map_err(|e| format!("day {day_num}: {e}"))?;
+
+    let mut records = vec![];
+    for &part in &args.parts {
+        let start = Instant::now();
+        let answer = parsed.solve(part);
+        let duration = start.elapsed();
+
+        let status = match answer {
+            Answer::Unsolved => Status::Unsolved,
+            Answer::Failed(_) => Status::Failed,
+            _ => Status::Ok,
+        };
+        let mut record = Record::new(day_num, part, status);
+        record.answer = Some(answer);
+        record.duration = Some(duration);
+        records.push(record);
     }
 
-    let day_num: usize = args.next().unwrap().parse()?;
-    let input_path = args.next().unwrap();
+    Ok(records)
+}
+
+fn bench(args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut benches = vec![];
+    for &day_num in &args.days {
+        let input = read_input(args, day_num)?;
+        let result = bench::bench_day(
+            day_num,
+            day(args, day_num),
+            &input,
+            &args.parts,
+            args.warmup,
+            args.iterations,
+        )
+        .map_err(|e| format!("day {day_num}: {e}"))?;
+        if args.format == Format::Text {
+            bench::print_day(&result);
+            println!();
+        }
+        benches.push(result);
+    }
 
-    let input = std::fs::read_to_string(input_path)?;
+    let baselines_dir = Path::new(&args.baselines_dir);
+    let comparisons = match &args.baseline {
+        Some(name) => {
+            let baseline = Baseline::load(baselines_dir, name)?;
+            baseline::compare(&baseline, args.year, &benches, args.threshold)
+        }
+        None => vec![],
+    };
+    let regressions = comparisons.iter().filter(|c| c.regressed).count();
+
+    if args.format == Format::Text {
+        if benches.len() > 1 {
+            bench::print_summary(&benches);
+        }
+        if let Some(name) = &args.baseline {
+            println!();
+            baseline::print_comparisons(name, &comparisons, args.threshold);
+        }
+    } else {
+        let records = benches
+            .iter()
+            .flat_map(|bench| {
+                bench.parts.iter().map(|p| {
+                    let comparison = comparisons.iter().find(|c| {
+                        c.day == bench.day && c.step == baseline::Step::from_part(p.part)
+                    });
+                    let status = match (&p.answer, comparison) {
+                        (_, Some(c)) if c.regressed => Status::Regressed,
+                        (Answer::Unsolved, _) => Status::Unsolved,
+                        (Answer::Failed(_), _) => Status::Failed,
+                        _ => Status::Ok,
+                    };
+                    let mut record = Record::new(bench.day, p.part, status);
+                    record.answer = Some(p.answer.clone());
+                    record.duration = Some(p.stats.median);
+                    record.stats = Some(p.stats);
+                    record.baseline = comparison.map(|c| c.baseline);
+                    record
+                })
+            })
+            .collect::<Vec<_>>();
+        report::print(args.format, &records);
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let path = Baseline::save(baselines_dir, name, args.year, &benches)?;
+        eprintln!("Saved baseline '{name}' to {}", path.display());
+    }
+
+    if regressions > 0 {
+        return Err(format!("{regressions} step(s) regressed").into());
+    }
+
+    Ok(())
+}
+
+fn check(args: &Args) -> Result<(), Box<dyn Error>> {
+    let answers = Answers::from_env(args.answers_dir.as_deref(), args.year);
+    let text = args.format == Format::Text;
+    let mut records = vec![];
+
+    for_each_day(
+        args,
+        |day_num| check_day(args, &answers, day_num),
+        |day_num, result| {
+            let (day_records, note) = result?;
+            if text {
+                match note {
+                    Some(note) => println!("Day {day_num}: {note}"),
+                    None => {
+                        for record in &day_records {
+                            println!(
+                                "Day {day_num} part {}: {}",
+                                record.part.number(),
+                                check_status(record)
+                            );
+                        }
+                    }
+                }
+            }
+            records.extend(day_records);
+            Ok(())
+        },
+    )?;
+
+    let count = |statuses: &[Status]| {
+        records
+            .iter()
+            .filter(|r| statuses.contains(&r.status))
+            .count()
+    };
+    let passed = count(&[Status::Pass]);
+    let failed = count(&[
+        Status::Fail,
+        Status::Failed,
+        Status::Panicked,
+        Status::Invalid,
+    ]);
+    let missing = count(&[Status::Missing, Status::Unsolved, Status::NoInput]);
 
-    if let Some(day) = days::DAYS.get(day_num - 1) {
-        day(input);
+    if text {
+        println!("{passed} passed, {failed} failed, {missing} missing");
     } else {
-        println!("Day not implemented yet");
+        report::print(args.format, &records);
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} part(s) failed").into());
+    }
+
+    Ok(())
+}
+
+/// Checks the selected parts of one day. For a day that couldn't be solved at all, also
+/// returns a note saying why.
+fn check_day(
+    args: &Args,
+    answers: &Answers,
+    day_num: usize,
+) -> Result<(Vec<Record>, Option<String>), String> {
+    let all_parts = |status| {
+        args.parts
+            .iter()
+            .map(|&part| Record::new(day_num, part, status))
+            .collect()
+    };
+
+    let input = match read_input(args, day_num) {
+        Ok(input) => input,
+        Err(e) => return Ok((all_parts(Status::NoInput), Some(format!("skipped, {e}")))),
+    };
+    let expected = answers.load(day_num, args.example)?;
+    let day = day(args, day_num);
+
+    let parsed = match panic::catch_unwind(AssertUnwindSafe(|| day.parse(&input))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => {
+            let note = format!("FAIL, invalid input, {e}");
+            return Ok((all_parts(Status::Invalid), Some(note)));
+        }
+        Err(_) => {
+            let note = "FAIL, parse panicked".to_string();
+            return Ok((all_parts(Status::Panicked), Some(note)));
+        }
+    };
+
+    let mut records = vec![];
+    for &part in &args.parts {
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part)));
+        let duration = start.elapsed();
+
+        let mut record = match result {
+            Ok(answer) => {
+                let status = match Verdict::new(expected.get(part), &answer) {
+                    Verdict::Pass => Status::Pass,
+                    Verdict::Fail(_) => Status::Fail,
+                    Verdict::Missing => Status::Missing,
+                    Verdict::Unsolved => Status::Unsolved,
+                    Verdict::Failed(_) => Status::Failed,
+                };
+                let mut record = Record::new(day_num, part, status);
+                record.answer = Some(answer);
+                record
+            }
+            Err(_) => Record::new(day_num, part, Status::Panicked),
+        };
+        record.expected = expected.get(part).map(String::from);
+        record.duration = Some(duration);
+        records.push(record);
+    }
+
+    Ok((records, None))
+}
+
+fn check_status(record: &Record) -> String {
+    let answer = record
+        .answer
+        .as_ref()
+        .map(Answer::to_string)
+        .unwrap_or_default();
+
+    match record.status {
+        Status::Pass => format!("pass ({answer})"),
+        Status::Fail => format!(
+            "FAIL, expected {} but got {answer}",
+            record.expected.as_deref().unwrap_or_default()
+        ),
+        Status::Missing => format!("missing answer (got {answer})"),
+        Status::Unsolved => "unsolved".to_string(),
+        Status::Failed => match &record.answer {
+            Some(Answer::Failed(reason)) => format!("FAIL, {reason}"),
+            _ => "FAIL".to_string(),
+        },
+        Status::Panicked => "FAIL, panicked".to_string(),
+        Status::Invalid => "FAIL, invalid input".to_string(),
+        Status::Ok | Status::NoInput | Status::Regressed => record.status.name().to_string(),
+    }
+}
+
+fn explore(args: &Args) -> Result<(), Box<dyn Error>> {
+    let day_num = args.days[0];
+    if args.input.as_deref() == Some("-") {
+        return Err("explore reads its commands from stdin, give the input as a file".into());
+    }
+
+    let input = read_input(args, day_num)?;
+    let parsed = day(args, day_num)
+        .parse(&input)
+        .map_err(|e| format!("day {day_num}: {e}"))?;
+
+    match parsed.explore(&mut std::io::stdin().lock()) {
+        Some(result) => Ok(result?),
+        None => Err(format!("day {day_num} has no explore mode").into()),
+    }
+}
+
+fn fetch(args: &Args) -> Result<(), Box<dyn Error>> {
+    let inputs = Inputs::from_env(args.inputs_dir.as_deref(), args.year);
+    let mut fetcher = Fetcher::new(&Config::from_env()?)?;
+
+    for &day_num in &args.days {
+        for fetched in fetcher.fetch(&inputs, args.year, day_num)? {
+            let verb = if fetched.cached { "cached" } else { "fetched" };
+            println!("Day {day_num}: {verb} {}", fetched.path.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(args: &Args) -> Result<(), Box<dyn Error>> {
+    let day_num = args.days[0];
+    let part = args.parts[0];
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let input = read_input(args, day_num)?;
+            let parsed = day(args, day_num)
+                .parse(&input)
+                .map_err(|e| format!("day {day_num}: {e}"))?;
+            match parsed.solve(part) {
+                Answer::Unsolved => {
+                    return Err(format!("day {day_num} part {} is unsolved", part.number()).into())
+                }
+                Answer::Failed(reason) => {
+                    return Err(
+                        format!("day {day_num} part {} failed, {reason}", part.number()).into(),
+                    )
+                }
+                answer => answer.to_string(),
+            }
+        }
     };
 
+    let answers = Answers::from_env(args.answers_dir.as_deref(), args.year);
+    let mut history = History::load(&answers.history_path())?;
+    if let Some(reason) = history.refusal(day_num, part, &answer) {
+        return Err(format!("not submitting {answer}, {reason}").into());
+    }
+
+    let submitter = Submitter::new(&Config::from_env()?)?;
+    let (outcome, message) = submitter.submit(args.year, day_num, part, &answer)?;
+    history.record(Attempt::now(day_num, part, outcome, &answer))?;
+
+    println!("Day {day_num} part {}: {answer}", part.number());
+    println!("{message}");
+    match outcome {
+        Outcome::Correct => Ok(()),
+        outcome => Err(format!("{answer} was not accepted ({})", outcome.name()).into()),
+    }
+}
+
+fn new_day(args: &Args) -> Result<(), Box<dyn Error>> {
+    let day_num = args.days[0];
+    let (days_dir, examples_dir) = scaffold::crate_dirs()?;
+    let registered = days::year(args.year).map_or(0, |year| year.days.len());
+    for path in scaffold::add_day(&days_dir, args.year, registered, day_num)? {
+        println!("Wrote {}", path.display());
+    }
+    for path in scaffold::add_example(&examples_dir, args.year, day_num)? {
+        println!("Created {}", path.display());
+    }
+
+    let inputs = Inputs::from_env(args.inputs_dir.as_deref(), args.year);
+    let input = inputs.year_path(&Inputs::file_name(day_num, false));
+    if scaffold::placeholder(&input, inputs.find(day_num, false))? {
+        println!("Created {}", input.display());
+    }
+
+    let answers = Answers::from_env(args.answers_dir.as_deref(), args.year);
+    let expected = answers.candidates(day_num, false).remove(0);
+    if scaffold::placeholder(&expected, answers.find(day_num, false))? {
+        println!("Created {}", expected.display());
+    }
+
     Ok(())
 }
+
+fn list(args: &Args) {
+    let inputs = Inputs::from_env(args.inputs_dir.as_deref(), args.year);
+    for &day_num in &args.days {
+        match inputs.find(day_num, args.example) {
+            Some(path) => println!("Day {day_num}: {}", path.display()),
+            None => println!("Day {day_num}: no input"),
+        }
+    }
+}
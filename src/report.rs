@@ -0,0 +1,239 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::bench::Stats;
+use crate::days::{Answer, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format '{s}', expected text, json or csv")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Pass,
+    Fail,
+    Missing,
+    Unsolved,
+    /// The day gave an [`Answer::Failed`].
+    Failed,
+    Panicked,
+    NoInput,
+    Invalid,
+    Regressed,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::NoInput => "no-input",
+            Status::Invalid => "invalid-input",
+            Status::Regressed => "regressed",
+        }
+    }
+}
+
+/// The outcome of one part of one day, as reported by `run`, `bench` and `check`.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: usize,
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub expected: Option<String>,
+    pub duration: Option<Duration>,
+    pub stats: Option<Stats>,
+    pub baseline: Option<Duration>,
+}
+
+impl Record {
+    pub fn new(day: usize, part: Part, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            status,
+            answer: None,
+            expected: None,
+            duration: None,
+            stats: None,
+            baseline: None,
+        }
+    }
+}
+
+const COLUMNS: [&str; 11] = [
+    "day",
+    "part",
+    "status",
+    "answer",
+    "expected",
+    "duration_ns",
+    "min_ns",
+    "median_ns",
+    "mean_ns",
+    "stddev_ns",
+    "baseline_ns",
+];
+
+fn nanos(d: Duration) -> String {
+    d.as_nanos().to_string()
+}
+
+/// The values of a record, in the order of [`COLUMNS`]. `None` marks a missing value.
+fn fields(record: &Record) -> [Option<String>; 11] {
+    let stat = |f: fn(&Stats) -> Duration| record.stats.as_ref().map(|s| nanos(f(s)));
+
+    [
+        Some(record.day.to_string()),
+        Some(record.part.number().to_string()),
+        Some(record.status.name().to_string()),
+        match &record.answer {
+            None | Some(Answer::Unsolved) => None,
+            Some(answer) => Some(answer.to_string()),
+        },
+        record.expected.clone(),
+        record.duration.map(nanos),
+        stat(|s| s.min),
+        stat(|s| s.median),
+        stat(|s| s.mean),
+        stat(|s| s.stddev),
+        record.baseline.map(nanos),
+    ]
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let mut out = String::from("[");
+    for (i, record) in records.iter().enumerate() {
+        out.push_str(if i == 0 { "\n  {" } else { ",\n  {" });
+
+        for (j, (column, value)) in COLUMNS.iter().zip(fields(record)).enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            let value = match (*column, value) {
+                (_, None) => "null".to_string(),
+                ("status" | "expected", Some(v)) => json_string(&v),
+                ("answer", Some(v)) => match &record.answer {
+                    Some(Answer::Int(_)) => v,
+                    _ => json_string(&v),
+                },
+                (_, Some(v)) => v,
+            };
+            write!(out, "\"{column}\": {value}").unwrap();
+        }
+
+        out.push('}');
+    }
+    out.push_str(if records.is_empty() { "]" } else { "\n]" });
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = COLUMNS.join(",");
+    out.push('\n');
+    for record in records {
+        let row = fields(record)
+            .map(|v| v.map(|v| csv_field(&v)).unwrap_or_default())
+            .join(",");
+        out.push_str(&row);
+        out.push('\n');
+    }
+    out
+}
+
+/// Prints the records in a machine readable format. Text output is left to each command.
+pub fn print(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(records)),
+        Format::Csv => print!("{}", to_csv(records)),
+    }
+}
+
+#[cfg(test)]
+mod report_test {
+    use std::time::Duration;
+
+    use super::{to_csv, to_json, Record, Status};
+    use crate::days::{Answer, Part};
+
+    fn records() -> Vec<Record> {
+        let mut one = Record::new(17, Part::One, Status::Ok);
+        one.answer = Some(Answer::Text("4,6,3".to_string()));
+        one.duration = Some(Duration::from_nanos(1500));
+
+        let mut two = Record::new(17, Part::Two, Status::Fail);
+        two.answer = Some(Answer::Int(117440));
+        two.expected = Some("3".to_string());
+
+        vec![one, two]
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            to_json(&records()),
+            "[\n  {\"day\": 17, \"part\": 1, \"status\": \"ok\", \"answer\": \"4,6,3\", \"expected\": null, \
+             \"duration_ns\": 1500, \"min_ns\": null, \"median_ns\": null, \"mean_ns\": null, \"stddev_ns\": null, \"baseline_ns\": null},\
+             \n  {\"day\": 17, \"part\": 2, \"status\": \"fail\", \"answer\": 117440, \"expected\": \"3\", \
+             \"duration_ns\": null, \"min_ns\": null, \"median_ns\": null, \"mean_ns\": null, \"stddev_ns\": null, \"baseline_ns\": null}\n]"
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "day,part,status,answer,expected,duration_ns,min_ns,median_ns,mean_ns,stddev_ns,baseline_ns\n\
+             17,1,ok,\"4,6,3\",,1500,,,,,\n\
+             17,2,fail,117440,3,,,,,,\n"
+        );
+    }
+}
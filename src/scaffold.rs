@@ -0,0 +1,353 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The directory holding the solutions, with a `yNNNN` module for each year, which
+/// `new` adds days to, relative to the crate it is run from.
+const DAYS_DIR: &str = "src/days";
+/// The directory holding the examples the generated tests check, in the same crate.
+const EXAMPLES_DIR: &str = "examples";
+pub const MAX_DAY: usize = 25;
+
+const YEAR_TEMPLATE: &str = r#"use super::Year;
+
+mod day1;
+
+pub const YEAR: Year = Year {
+    year: {year},
+    days: &[
+        &day1::Day1,
+    ],
+};
+"#;
+
+const TEMPLATE: &str = r#"use crate::days::{parse, Answer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> parse::Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+"#;
+
+/// The answers to a new day's example, which fail its generated tests until they are
+/// replaced with the puzzle's.
+const EXAMPLE_TEMPLATE: &str = r#"# The answers to {name}.txt. Replace them with the puzzle's, or remove a part
+# to leave it untested.
+part1 = "todo"
+part2 = "todo"
+"#;
+
+/// The crate in the current directory, which `new` must be run from, as its solutions
+/// and examples directories.
+pub fn crate_dirs() -> Result<(PathBuf, PathBuf), String> {
+    let days_dir = PathBuf::from(DAYS_DIR);
+    if !days_dir.join("mod.rs").is_file() {
+        return Err(format!(
+            "no {DAYS_DIR}/mod.rs in the current directory, run this from the crate root"
+        ));
+    }
+    Ok((days_dir, PathBuf::from(EXAMPLES_DIR)))
+}
+
+/// Adds a solution module for `day` of `year` to the `yNNNN` module in `days_dir`
+/// from the template, and registers it there, starting the year's module with it if
+/// the year has no days yet. Days are numbered by their place in the year, so the new day
+/// must directly follow the `registered` ones. Returns the files written.
+pub fn add_day(
+    days_dir: &Path,
+    year: u32,
+    registered: usize,
+    day: usize,
+) -> Result<Vec<PathBuf>, String> {
+    if day <= registered {
+        return Err(format!("day {day} of {year} already exists"));
+    }
+    if day != registered + 1 {
+        return Err(format!(
+            "days are added in order, the next one of {year} is day {}",
+            registered + 1
+        ));
+    }
+
+    let year_dir = days_dir.join(format!("y{year}"));
+    let module = year_dir.join(format!("day{day}.rs"));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    let registry = year_dir.join("mod.rs");
+    let mut written = vec![module.clone(), registry.clone()];
+    if registered == 0 {
+        if registry.exists() {
+            return Err(format!("{} already exists", registry.display()));
+        }
+        let years = days_dir.join("mod.rs");
+        let contents =
+            register_year(&read(&years)?, year).map_err(|e| format!("{}: {e}", years.display()))?;
+
+        fs::create_dir_all(&year_dir).map_err(|e| format!("{}: {e}", year_dir.display()))?;
+        write(
+            &registry,
+            &YEAR_TEMPLATE.replace("{year}", &year.to_string()),
+        )?;
+        write(&years, &contents)?;
+        written.push(years);
+    } else {
+        let contents =
+            register(&read(&registry)?, day).map_err(|e| format!("{}: {e}", registry.display()))?;
+        write(&registry, &contents)?;
+    }
+    write(&module, &TEMPLATE.replace("{day}", &day.to_string()))?;
+
+    Ok(written)
+}
+
+/// Adds an empty example for `day` of `year` to `examples_dir`, with answers that the
+/// test generated for it fails on until they are filled in. Returns the files written,
+/// leaving any that already exist alone.
+pub fn add_example(examples_dir: &Path, year: u32, day: usize) -> Result<Vec<PathBuf>, String> {
+    let dir = examples_dir.join(year.to_string());
+    let name = format!("day{day:02}-example");
+    let mut written = vec![];
+    for (ext, contents) in [
+        ("txt", String::new()),
+        ("toml", EXAMPLE_TEMPLATE.replace("{name}", &name)),
+    ] {
+        let path = dir.join(format!("{name}.{ext}"));
+        if path.exists() {
+            continue;
+        }
+        fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        write(&path, &contents)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// The number in a `<prefix>N<suffix>` line, like the 3 of `mod day3;`.
+fn numbered(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// Adds `mod dayN;` after the last day module and `&dayN::DayN,` to the end of the
+/// year's days.
+fn register(registry: &str, day: usize) -> Result<String, String> {
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+
+    let last_mod = lines
+        .iter()
+        .rposition(|line| numbered(line, "mod day", ";").is_some())
+        .ok_or("no `mod dayN;` line to add the new day after")?;
+    lines.insert(last_mod + 1, format!("mod day{day};"));
+
+    let last_entry = lines
+        .iter()
+        .rposition(|line| {
+            line.trim_start()
+                .strip_prefix("&day")
+                .and_then(|entry| entry.split_once("::"))
+                .is_some_and(|(n, _)| n.parse::<usize>().is_ok())
+        })
+        .ok_or("no `&dayN::DayN,` entry to add the new day after")?;
+    let indent =
+        &lines[last_entry][..lines[last_entry].len() - lines[last_entry].trim_start().len()];
+    lines.insert(last_entry + 1, format!("{indent}&day{day}::Day{day},"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `mod yNNNN;` and `yNNNN::YEAR` to the registry of years, keeping them in order.
+fn register_year(registry: &str, year: u32) -> Result<String, String> {
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, numbered(line, "mod y", ";")?)))
+        .collect::<Vec<_>>();
+    let (&(last, _), _) = mods
+        .split_last()
+        .ok_or("no `mod yNNNN;` line to add the year after")?;
+    let at = mods
+        .iter()
+        .find(|&&(_, y)| y > year)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, format!("mod y{year};"));
+
+    let list = lines
+        .iter_mut()
+        .find(|line| line.starts_with("pub const YEARS: &[Year] = &["))
+        .ok_or("no `YEARS` list to add the year to")?;
+    let mut years = parse_years(list).ok_or("expected `YEARS` on a single line")?;
+    years.push(year);
+    years.sort_unstable();
+    *list = format!(
+        "pub const YEARS: &[Year] = &[{}];",
+        years
+            .iter()
+            .map(|y| format!("y{y}::YEAR"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn parse_years(list: &str) -> Option<Vec<u32>> {
+    list.strip_prefix("pub const YEARS: &[Year] = &[")?
+        .strip_suffix("];")?
+        .split(',')
+        .map(|entry| numbered(entry.trim(), "y", "::YEAR"))
+        .collect()
+}
+
+/// Creates an empty file at `path` to be filled in later, unless `existing` shows there
+/// already is one. Returns whether it was created.
+pub fn placeholder(path: &Path, existing: Option<PathBuf>) -> Result<bool, String> {
+    if existing.is_some() {
+        return Ok(false);
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    fs::write(path, "").map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod scaffold_test {
+    use std::fs;
+
+    use crate::answers::Expected;
+
+    use super::{add_day, add_example, register, register_year};
+
+    const YEARS: &str = "pub mod grid;
+mod y2024;
+
+pub const YEARS: &[Year] = &[y2024::YEAR];
+";
+
+    const YEAR: &str = "use super::Year;
+
+mod day1;
+mod day2;
+
+pub const YEAR: Year = Year {
+    year: 2024,
+    days: &[
+        &day1::Day1,
+        &day2::Day2,
+    ],
+};
+";
+
+    #[test]
+    fn test_register() {
+        let registry = register(YEAR, 3).unwrap();
+        assert!(registry.contains("mod day2;\nmod day3;\n\n"));
+        assert!(registry.contains("        &day2::Day2,\n        &day3::Day3,\n    ],"));
+        assert!(register("pub mod grid;\n", 1).is_err());
+
+        assert_eq!(
+            register_year(YEARS, 2023).unwrap(),
+            "pub mod grid;
+mod y2023;
+mod y2024;
+
+pub const YEARS: &[Year] = &[y2023::YEAR, y2024::YEAR];
+"
+        );
+        assert!(register_year(YEARS, 2025)
+            .unwrap()
+            .contains("mod y2024;\nmod y2025;\n"));
+    }
+
+    #[test]
+    fn test_add_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("y2024")).unwrap();
+        fs::write(dir.join("mod.rs"), YEARS).unwrap();
+        fs::write(dir.join("y2024/mod.rs"), YEAR).unwrap();
+
+        assert!(add_day(&dir, 2024, 2, 2)
+            .unwrap_err()
+            .contains("already exists"));
+        assert!(add_day(&dir, 2024, 2, 5).is_err());
+        add_day(&dir, 2024, 2, 3).unwrap();
+        let module = fs::read_to_string(dir.join("y2024/day3.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day3 {"));
+        assert!(fs::read_to_string(dir.join("y2024/mod.rs"))
+            .unwrap()
+            .contains("mod day3;"));
+
+        // A stray module file is never overwritten.
+        fs::write(dir.join("y2024/mod.rs"), YEAR).unwrap();
+        assert!(add_day(&dir, 2024, 2, 3)
+            .unwrap_err()
+            .contains("day3.rs already exists"));
+        assert_eq!(fs::read_to_string(dir.join("y2024/mod.rs")).unwrap(), YEAR);
+
+        // The first day of a year starts its module.
+        assert!(add_day(&dir, 2023, 0, 2).is_err());
+        add_day(&dir, 2023, 0, 1).unwrap();
+        assert!(dir.join("y2023/day1.rs").is_file());
+        assert!(fs::read_to_string(dir.join("y2023/mod.rs"))
+            .unwrap()
+            .contains("    year: 2023,"));
+        assert!(fs::read_to_string(dir.join("mod.rs"))
+            .unwrap()
+            .contains("&[y2023::YEAR, y2024::YEAR]"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_add_example() {
+        let dir = std::env::temp_dir().join(format!("aoc-example-test-{}", std::process::id()));
+        let written = add_example(&dir, 2024, 3).unwrap();
+        assert_eq!(
+            written,
+            [
+                dir.join("2024/day03-example.txt"),
+                dir.join("2024/day03-example.toml")
+            ]
+        );
+        let expected = Expected::parse(&fs::read_to_string(&written[1]).unwrap()).unwrap();
+        assert_eq!(expected.part1.as_deref(), Some("todo"));
+        assert_eq!(expected.part2.as_deref(), Some("todo"));
+
+        // Examples that are already there are kept.
+        fs::write(&written[0], "1 2\n").unwrap();
+        assert!(add_example(&dir, 2024, 3).unwrap().is_empty());
+        assert_eq!(fs::read_to_string(&written[0]).unwrap(), "1 2\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{config::Config, days::Part, http};
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last wrong answer, so it wasn't judged.
+    Wait,
+    /// The part was already solved, so it wasn't judged.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 7] = [
+        Outcome::Correct,
+        Outcome::Wrong,
+        Outcome::TooHigh,
+        Outcome::TooLow,
+        Outcome::Wait,
+        Outcome::AlreadySolved,
+        Outcome::Unknown,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wait => "wait",
+            Outcome::AlreadySolved => "already-solved",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    /// Reads the outcome from the text of the page answering a submission.
+    pub fn parse(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::Wait
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn rejected(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+/// One submitted answer, as recorded in the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: usize,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+impl Attempt {
+    pub fn now(day: usize, part: Part, outcome: Outcome, answer: &str) -> Self {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self {
+            time,
+            day,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+}
+
+const HISTORY_HEADER: &str = "time,day,part,outcome,answer";
+
+/// Every answer submitted so far, kept in a CSV file with the answer last so that it
+/// may contain commas.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Reads the history at `path`. A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut history = Self {
+            path: path.to_path_buf(),
+            attempts: vec![],
+        };
+        if !path.is_file() {
+            return Ok(history);
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        for (i, line) in contents.lines().enumerate().skip(1) {
+            let attempt = parse_attempt(line)
+                .ok_or_else(|| format!("{}: line {}: invalid attempt", path.display(), i + 1))?;
+            history.attempts.push(attempt);
+        }
+
+        Ok(history)
+    }
+
+    /// Why `answer` shouldn't be submitted, if what is already known rules it out:
+    /// the part is solved, the answer was rejected before, or it is past an answer
+    /// the site said was too high or too low.
+    pub fn refusal(&self, day: usize, part: Part, answer: &str) -> Option<String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part)
+            .collect::<Vec<_>>();
+
+        if let Some(correct) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return Some(format!(
+                "the part was already solved with {}",
+                correct.answer
+            ));
+        }
+        if let Some(rejected) = attempts
+            .iter()
+            .find(|a| a.answer == answer && a.outcome.rejected())
+        {
+            return Some(format!(
+                "it was already rejected as {}",
+                rejected.outcome.name()
+            ));
+        }
+
+        let n = answer.parse::<i128>().ok()?;
+        let bound = |outcome| {
+            attempts
+                .iter()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).filter(|&high| n >= high).min() {
+            return Some(format!("{high} was already too high"));
+        }
+        if let Some(low) = bound(Outcome::TooLow).filter(|&low| n <= low).max() {
+            return Some(format!("{low} was already too low"));
+        }
+
+        None
+    }
+
+    /// Appends `attempt` to the history file, creating it if needed.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        let error = |e: std::io::Error| format!("{}: {e}", self.path.display());
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+
+        let new = !self.path.is_file();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(error)?;
+        if new {
+            writeln!(file, "{HISTORY_HEADER}").map_err(error)?;
+        }
+        writeln!(
+            file,
+            "{},{},{},{},{}",
+            attempt.time,
+            attempt.day,
+            attempt.part.number(),
+            attempt.outcome.name(),
+            attempt.answer
+        )
+        .map_err(error)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(5, ',');
+    let time = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = Part::parse(fields.next()?).ok()?;
+    let outcome = fields.next()?;
+    let outcome = Outcome::ALL.into_iter().find(|o| o.name() == outcome)?;
+    let answer = fields.next()?.to_string();
+
+    Some(Attempt {
+        time,
+        day,
+        part,
+        outcome,
+        answer,
+    })
+}
+
+/// Posts answers to the site.
+pub struct Submitter {
+    base_url: String,
+    session: String,
+}
+
+impl Submitter {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        Ok(Self {
+            base_url: config.base_url.clone(),
+            session: config.session()?.to_string(),
+        })
+    }
+
+    /// Submits `answer` for a part, returning the outcome with the site's message.
+    pub fn submit(
+        &self,
+        year: u32,
+        day: usize,
+        part: Part,
+        answer: &str,
+    ) -> Result<(Outcome, String), String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let cookie = format!("session={}", self.session);
+        let level = part.number().to_string();
+        let body = http::form_encode(&[("level", &level), ("answer", answer)]);
+        let response = http::request(
+            "POST",
+            &url,
+            &[("Cookie", &cookie), ("User-Agent", http::USER_AGENT)],
+            Some(&body),
+        )?;
+        if response.status != 200 {
+            return Err(format!("{url}: HTTP {}", response.status));
+        }
+
+        let message = message(&response.body);
+        Ok((Outcome::parse(&message), message))
+    }
+}
+
+/// The text of the `<article>` holding the site's verdict, on a single line.
+fn message(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let text = http::html_text(article);
+    let text = text.split("[Return to").next().unwrap_or_default();
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod submit_test {
+    use super::{message, Attempt, History, Outcome, Submitter};
+    use crate::{
+        config::Config,
+        days::Part,
+        http::{self, stub},
+    };
+
+    fn page(text: &str) -> String {
+        format!("<html><main><article><p>{text} <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main></html>")
+    }
+
+    #[test]
+    fn test_outcome() {
+        let cases = [
+            ("That's the right answer! You are <em>one gold star</em> closer.", Outcome::Correct),
+            ("That's not the right answer; your answer is too high. Please wait one minute.", Outcome::TooHigh),
+            ("That's not the right answer; your answer is too low.", Outcome::TooLow),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data.", Outcome::Wrong),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait.", Outcome::Wait),
+            ("You don't seem to be solving the right level. Did you already complete it?", Outcome::AlreadySolved),
+            ("Something else entirely.", Outcome::Unknown),
+        ];
+        for (text, outcome) in cases {
+            assert_eq!(Outcome::parse(&message(&page(text))), outcome, "{text}");
+        }
+
+        assert_eq!(
+            message(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            "That's the right answer! You are one gold star closer."
+        );
+    }
+
+    #[test]
+    fn test_refusal() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-test-{}", std::process::id()));
+        let path = dir.join("submissions.csv");
+        let mut history = History::load(&path).unwrap();
+
+        let attempts = [
+            (Part::One, Outcome::TooHigh, "100"),
+            (Part::One, Outcome::TooLow, "10"),
+            (Part::One, Outcome::Wrong, "50"),
+            (Part::Two, Outcome::Wrong, "4,6,3"),
+            (Part::Two, Outcome::Correct, "4,6,2"),
+        ];
+        for (part, outcome, answer) in attempts {
+            history
+                .record(Attempt::now(7, part, outcome, answer))
+                .unwrap();
+        }
+        assert!(history.refusal(7, Part::One, "100").is_some());
+        assert!(history.refusal(7, Part::One, "150").is_some());
+        assert!(history.refusal(7, Part::One, "5").is_some());
+        assert!(history.refusal(7, Part::One, "50").is_some());
+        assert_eq!(history.refusal(7, Part::One, "60"), None);
+        assert!(history.refusal(7, Part::Two, "1,2,3").is_some());
+        assert_eq!(history.refusal(8, Part::One, "100"), None);
+
+        // The answers with commas survive a round trip through the file.
+        let loaded = History::load(&path).unwrap();
+        assert_eq!(loaded.attempts, history.attempts);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, seen) = stub::serve(|request| {
+            let text = match request.body.as_str() {
+                "level=1&answer=42" => "That's the right answer!",
+                "level=1&answer=100" => "That's not the right answer; your answer is too high.",
+                _ => "That's not the right answer.",
+            };
+            ("200 OK", page(text))
+        });
+        let config = Config {
+            session: Some("token".to_string()),
+            base_url,
+            ..Config::default()
+        };
+        let submitter = Submitter::new(&config).unwrap();
+
+        let (outcome, _) = submitter.submit(2024, 1, Part::One, "100").unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        let (outcome, message) = submitter.submit(2024, 1, Part::One, "42").unwrap();
+        assert_eq!(
+            (outcome, message.as_str()),
+            (Outcome::Correct, "That's the right answer!")
+        );
+        assert_eq!(
+            submitter.submit(2024, 1, Part::Two, "1,2").unwrap().0,
+            Outcome::Wrong
+        );
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen[0].line, "POST /2024/day/1/answer HTTP/1.1");
+        assert!(seen[0].has_header("Cookie: session=token"));
+        assert!(seen[0].has_header(&format!("User-Agent: {}", http::USER_AGENT)));
+        assert_eq!(seen[2].body, "level=2&answer=1%2C2");
+    }
+}